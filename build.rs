@@ -0,0 +1,87 @@
+use std::{env, fs, path::Path};
+
+/// The entries of `dir` named `<prefix>N<suffix>`, with their number and path,
+/// in order
+fn numbered<N: std::str::FromStr + Ord>(
+    dir: &Path,
+    prefix: &str,
+    suffix: &str,
+) -> Vec<(N, String)> {
+    let mut entries: Vec<(N, String)> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let n = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix(prefix)?
+                .strip_suffix(suffix)?
+                .parse()
+                .ok()?;
+            Some((n, path.to_str()?.to_string()))
+        })
+        .collect();
+    entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    entries
+}
+
+/// Declares a public module for every `src/solutions/yYYYY/dayN.rs`, so adding
+/// a day is just a matter of creating its file, and a test for every
+/// `examples/YYYY/dayN/<name>.txt`, so adding an example is just a matter of
+/// creating the fixture
+fn main() {
+    let root = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).to_path_buf();
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).to_path_buf();
+
+    let dir = root.join("src/solutions");
+    println!("cargo:rerun-if-changed={}", dir.display());
+    let mut modules = String::new();
+    for (year, year_dir) in numbered::<i32>(&dir, "y", "") {
+        println!("cargo:rerun-if-changed={}", year_dir);
+        modules.push_str(&format!("pub mod y{} {{\n", year));
+        for (day, path) in numbered::<u32>(Path::new(&year_dir), "day", ".rs") {
+            modules.push_str(&format!(
+                "    #[path = {:?}]\n    pub mod day{};\n",
+                path, day
+            ));
+        }
+        modules.push_str("}\n");
+    }
+    fs::write(out.join("solutions.rs"), modules).unwrap();
+
+    let dir = root.join("examples");
+    println!("cargo:rerun-if-changed={}", dir.display());
+    let mut tests = String::new();
+    for (year, year_dir) in numbered::<i32>(&dir, "", "") {
+        println!("cargo:rerun-if-changed={}", year_dir);
+        for (day, path) in numbered::<u32>(Path::new(&year_dir), "day", "") {
+            println!("cargo:rerun-if-changed={}", path);
+            let mut names: Vec<String> = fs::read_dir(&path)
+                .unwrap()
+                .filter_map(|entry| {
+                    let path = entry.ok()?.path();
+                    Some(
+                        path.file_name()?
+                            .to_str()?
+                            .strip_suffix(".txt")?
+                            .to_string(),
+                    )
+                })
+                .collect();
+            names.sort_unstable();
+
+            for name in names {
+                let test_name: String = name
+                    .chars()
+                    .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                    .collect();
+                tests.push_str(&format!(
+                    "#[test]\nfn test_y{}_day{}_{}() {{\n    fixtures::assert_fixture(&examples_dir(), {}, {}, {:?});\n}}\n\n",
+                    year, day, test_name, year, day, name
+                ));
+            }
+        }
+    }
+    fs::write(out.join("fixtures.rs"), tests).unwrap();
+}
@@ -0,0 +1,231 @@
+//! Expected answers and checking results against them
+
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs,
+    io::ErrorKind,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::solver::{Answer, Failure, PartResult};
+
+/// Where `aoc verify --record` keeps the answers known to be right
+pub const ANSWERS_PATH: &str = "answers.toml";
+
+/// The expected answers of a day, as rendered by [`Answer`]'s `Display`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    /// The answer to part 1, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    /// The answer to part 2, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+/// Expected answers by year and day, as stored in `answers.toml` under
+/// `[2022.1]` tables
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<i32, BTreeMap<u32, DayAnswers>>);
+
+impl Answers {
+    /// Loads the answers file, a missing file has no answers yet
+    pub fn load(path: &str) -> Result<Self, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(raw) => Ok(toml::from_str(&raw).map_err(|e| format!("{}: {}", path, e))?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {}", path, e).into()),
+        }
+    }
+
+    /// Writes every answer back to `path`, sorted by year and day
+    pub fn save(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// The expected answer to `part` of `day` of `year`, if there is one
+    pub fn get(&self, year: i32, day: u32, part: u8) -> Option<&String> {
+        let answers = self.0.get(&year)?.get(&day)?;
+        match part {
+            1 => answers.part1.as_ref(),
+            _ => answers.part2.as_ref(),
+        }
+    }
+
+    /// Stores the answer of `result` as the expected one, if it has any
+    pub fn record(&mut self, result: &PartResult) {
+        if let Some(answer) = &result.answer {
+            let answers = self
+                .0
+                .entry(result.year)
+                .or_default()
+                .entry(result.day)
+                .or_default();
+            match result.part {
+                1 => answers.part1 = Some(answer.to_string()),
+                _ => answers.part2 = Some(answer.to_string()),
+            }
+        }
+    }
+}
+
+/// How a part fared against its expected answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The answer is the expected one
+    Pass,
+    /// The answer differs from the expected one, or there is none
+    Fail,
+    /// There is no expected answer to compare with
+    Missing,
+    /// The day could not be run at all
+    Error,
+    /// The part panicked
+    Panic,
+    /// The part ran past its timeout
+    Timeout,
+    /// Parsing and preparing the input ran past the timeout
+    InputTimeout,
+    /// The part was not run because an earlier one timed out
+    Skipped,
+}
+
+impl Status {
+    /// Whether the part should make `aoc verify` fail
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Status::Pass | Status::Missing)
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let status = match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+            Status::Error => "ERROR",
+            Status::Panic => "PANIC",
+            Status::Timeout => "TIMEOUT",
+            Status::InputTimeout => "INPUT TIMEOUT",
+            Status::Skipped => "SKIPPED",
+        };
+        f.pad(status)
+    }
+}
+
+/// A part's answer checked against the expected one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    /// The year of the day
+    pub year: i32,
+    /// The day of the puzzle
+    pub day: u32,
+    /// 1 or 2
+    pub part: u8,
+    /// How the answer compares
+    pub status: Status,
+    /// What the solver answered, if anything
+    pub answer: Option<Answer>,
+    /// What `answers.toml` expects, if anything
+    pub expected: Option<String>,
+}
+
+impl Check {
+    /// Checks `result` against its expected answer in `answers`
+    pub fn new(result: &PartResult, answers: &Answers) -> Self {
+        let expected = answers.get(result.year, result.day, result.part).cloned();
+        let status = match (&result.failure, &result.answer, &expected) {
+            (Some(Failure::Panic(_)), _, _) => Status::Panic,
+            (Some(Failure::Timeout(_)), _, _) => Status::Timeout,
+            (Some(Failure::InputTimeout(_)), _, _) => Status::InputTimeout,
+            (Some(Failure::Skipped), _, _) => Status::Skipped,
+            (None, _, None) => Status::Missing,
+            (None, Some(answer), Some(expected)) if answer.to_string() == *expected => Status::Pass,
+            _ => Status::Fail,
+        };
+
+        Self {
+            year: result.year,
+            day: result.day,
+            part: result.part,
+            status,
+            answer: result.answer.clone(),
+            expected,
+        }
+    }
+
+    /// A part that could not be run, e.g. because its input is missing
+    pub fn error(year: i32, day: u32, part: u8, answers: &Answers) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            status: Status::Error,
+            answer: None,
+            expected: answers.get(year, day, part).cloned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn result(day: u32, part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            year: 2022,
+            day,
+            part,
+            answer: answer.map(Answer::from),
+            failure: None,
+            parse_time: Duration::ZERO,
+            prepare_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let answers: Answers =
+            toml::from_str("[2022.1]\npart1 = \"24000\"\npart2 = \"45000\"").unwrap();
+        let status = |r| Check::new(&r, &answers).status;
+
+        assert_eq!(status(result(1, 1, Some("24000"))), Status::Pass);
+        assert_eq!(status(result(1, 2, Some("45001"))), Status::Fail);
+        assert_eq!(status(result(1, 2, None)), Status::Fail);
+        assert_eq!(status(result(2, 1, Some("15"))), Status::Missing);
+        let other_year = PartResult {
+            year: 2023,
+            ..result(1, 1, Some("24000"))
+        };
+        assert_eq!(status(other_year), Status::Missing);
+        let timed_out = PartResult {
+            failure: Some(Failure::Timeout(Duration::from_secs(1))),
+            ..result(1, 1, None)
+        };
+        assert_eq!(status(timed_out), Status::Timeout);
+        assert!(Status::Timeout.is_failure());
+        let skipped = PartResult {
+            failure: Some(Failure::Skipped),
+            ..result(1, 2, None)
+        };
+        assert_eq!(status(skipped), Status::Skipped);
+    }
+
+    #[test]
+    fn test_record() {
+        let mut answers = Answers::default();
+        answers.record(&result(10, 1, Some("13140")));
+        answers.record(&result(2, 2, Some("12")));
+        answers.record(&result(2, 1, None));
+
+        let expected = "[2022.2]\npart2 = \"12\"\n\n[2022.10]\npart1 = \"13140\"\n";
+        assert_eq!(toml::to_string(&answers).unwrap(), expected);
+    }
+}
@@ -0,0 +1,149 @@
+//! Repeated timing of parsing and each part
+
+use std::{
+    fmt::{self, Display, Formatter},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// How many times each stage runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Runs before measuring, which are not timed
+    pub warmup: usize,
+    /// Timed runs, at least one
+    pub iterations: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 2,
+            iterations: 10,
+        }
+    }
+}
+
+/// What a benchmark times
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// [`Solver::parse_input`](crate::solver::Solver::parse_input)
+    Parse,
+    /// [`Solver::prepare`](crate::solver::Solver::prepare)
+    Prepare,
+    /// Solving part 1 or 2
+    Part(u8),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Prepare => f.pad("prepare"),
+            Stage::Part(part) => f.pad(&format!("part {}", part)),
+        }
+    }
+}
+
+/// Summary of the timed iterations of a stage
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    /// The fastest run
+    pub min: Duration,
+    /// The middle run, or the mean of the two middle ones
+    pub median: Duration,
+    /// The average run
+    pub mean: Duration,
+    /// The sample standard deviation, 0 for a single run
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// The statistics of `samples`, which must not be empty
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+        samples.sort_unstable();
+
+        let n = samples.len();
+        let median = match n % 2 {
+            0 => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+            _ => samples[n / 2],
+        };
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+        let variance = samples
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / (n.max(2) - 1) as f64;
+
+        Self {
+            min: samples[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// The timings of one stage of a day
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchResult {
+    /// The day of the puzzle
+    pub day: u32,
+    /// What was timed
+    pub stage: Stage,
+    /// How long it took
+    pub stats: Stats,
+}
+
+/// Times `routine` on a fresh value from `setup` every iteration, only the
+/// routine itself is measured
+pub fn measure<I, O>(
+    options: &BenchOptions,
+    mut setup: impl FnMut() -> I,
+    mut routine: impl FnMut(I) -> O,
+) -> Stats {
+    for _ in 0..options.warmup {
+        black_box(routine(black_box(setup())));
+    }
+
+    let samples = (0..options.iterations.max(1))
+        .map(|_| {
+            let input = setup();
+            let before = Instant::now();
+            let output = routine(black_box(input));
+            let elapsed = before.elapsed();
+            black_box(output);
+            elapsed
+        })
+        .collect();
+
+    Stats::new(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis).to_vec();
+        let stats = Stats::new(samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1290);
+    }
+
+    #[test]
+    fn test_measure() {
+        let options = BenchOptions {
+            warmup: 1,
+            iterations: 5,
+        };
+        let mut calls = 0;
+        measure(&options, || 2, |x| calls += x);
+        assert_eq!(calls, 12);
+    }
+}
@@ -0,0 +1,268 @@
+//! Example inputs from the puzzle text, with the answers they should give.
+//!
+//! A fixture is `examples/YYYY/dayN/<name>.txt`, next to an optional
+//! `examples/YYYY/dayN/<name>.toml` with the expected answers and any parameters
+//! the example needs:
+//!
+//! ```toml
+//! part1 = "26"
+//! part2 = "56000011"
+//!
+//! [params]
+//! row = 10
+//! at_most = 20
+//! ```
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::registry;
+
+/// Where the fixtures live, relative to the workspace root
+pub const EXAMPLES_DIR: &str = "examples";
+/// The fixture used when `--example` is given without a name
+pub const DEFAULT_NAME: &str = "example";
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+struct Sidecar {
+    part1: Option<toml::Value>,
+    part2: Option<toml::Value>,
+    #[serde(default)]
+    params: toml::Table,
+}
+
+/// Strings stay as they are, numbers and booleans as written in toml
+fn value_string(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// An example from the puzzle text with its expected answers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    /// The year of the day
+    pub year: i32,
+    /// The day of the puzzle
+    pub day: u32,
+    /// The file name without `.txt`, e.g. `example`
+    pub name: String,
+    /// The raw input, as read
+    pub input: String,
+    /// The expected answers of both parts, if known
+    pub expected: [Option<String>; 2],
+    /// Parameters to apply before solving, sorted by name
+    pub params: Vec<(String, String)>,
+}
+
+/// `dir/YYYY/dayN`, where the fixtures of a day live
+pub fn day_dir(dir: &Path, year: i32, day: u32) -> PathBuf {
+    dir.join(year.to_string()).join(format!("day{}", day))
+}
+
+/// `dir/YYYY/dayN/<name>.txt`, the input of a fixture
+pub fn input_path(dir: &Path, year: i32, day: u32, name: &str) -> PathBuf {
+    day_dir(dir, year, day).join(format!("{}.txt", name))
+}
+
+/// The names of the fixtures of `day` of `year`, in order
+pub fn names(dir: &Path, year: i32, day: u32) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(day_dir(dir, year, day)) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?.strip_suffix(".txt")?;
+            Some(name.to_string())
+        })
+        .collect();
+    names.sort_unstable();
+    names
+}
+
+impl Fixture {
+    /// Reads the fixture `name` of `day` of `year` from `dir`, along with
+    /// its `.toml` sidecar if there is one
+    pub fn load(
+        dir: &Path,
+        year: i32,
+        day: u32,
+        name: &str,
+    ) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let path = input_path(dir, year, day, name);
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                return Err(format!("Day {} of {} has no example {}", day, year, name).into())
+            }
+            Err(e) => return Err(format!("{}: {}", path.display(), e).into()),
+        };
+
+        let path = path.with_extension("toml");
+        let sidecar: Sidecar = match fs::read_to_string(&path) {
+            Ok(raw) => toml::from_str(&raw).map_err(|e| format!("{}: {}", path.display(), e))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => Sidecar::default(),
+            Err(e) => return Err(format!("{}: {}", path.display(), e).into()),
+        };
+
+        Ok(Fixture {
+            year,
+            day,
+            name: name.to_string(),
+            input,
+            expected: [
+                sidecar.part1.as_ref().map(value_string),
+                sidecar.part2.as_ref().map(value_string),
+            ],
+            params: sidecar
+                .params
+                .iter()
+                .map(|(name, value)| (name.clone(), value_string(value)))
+                .collect(),
+        })
+    }
+}
+
+/// A part that did not give the answer its fixture expects
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// 1 or 2
+    pub part: u8,
+    /// What the fixture expects
+    pub expected: String,
+    /// What the solver answered, if anything
+    pub actual: Option<String>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match &self.actual {
+            Some(actual) => write!(
+                f,
+                "part {}: expected\n{}\ngot\n{}",
+                self.part, self.expected, actual
+            ),
+            None => write!(
+                f,
+                "part {}: expected\n{}\ngot no answer",
+                self.part, self.expected
+            ),
+        }
+    }
+}
+
+/// Runs the registered solver of the fixture's day on it, returning the parts
+/// whose answer differs from the expected one
+pub fn check(fixture: &Fixture) -> Result<Vec<Mismatch>, Box<dyn Error + Send + Sync>> {
+    let registration = registry::get(fixture.year, fixture.day).ok_or_else(|| {
+        format!(
+            "Day {} of {} has not been solved yet",
+            fixture.day, fixture.year
+        )
+    })?;
+    let mut solver = (registration.solver)();
+    for (name, value) in &fixture.params {
+        solver
+            .set_param(name, value)
+            .map_err(|e| format!("Day {} example {}: {}", fixture.day, fixture.name, e))?;
+    }
+
+    let results = solver.run(fixture.year, fixture.day, fixture.input.clone(), None)?;
+    Ok(results
+        .into_iter()
+        .filter_map(|result| {
+            let expected = fixture.expected[result.part as usize - 1].clone()?;
+            let actual = result.answer.map(|a| a.to_string());
+            (actual.as_ref() != Some(&expected)).then_some(Mismatch {
+                part: result.part,
+                expected,
+                actual,
+            })
+        })
+        .collect())
+}
+
+/// Loads and checks a fixture, panicking with every mismatch. Meant for the
+/// tests generated for each fixture.
+pub fn assert_fixture(dir: &Path, year: i32, day: u32, name: &str) {
+    let fixture = Fixture::load(dir, year, day, name).unwrap_or_else(|e| panic!("{}", e));
+    let mismatches = check(&fixture).unwrap_or_else(|e| panic!("{}", e));
+    if !mismatches.is_empty() {
+        let mismatches: Vec<String> = mismatches.iter().map(Mismatch::to_string).collect();
+        panic!(
+            "Day {} of {} example {}\n{}",
+            day,
+            year,
+            name,
+            mismatches.join("\n")
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_load() {
+        let dir = env::temp_dir().join(format!("aoc-fixtures-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(day_dir(&dir, 2015, 25)).unwrap();
+        fs::write(input_path(&dir, 2015, 25, "example"), "1 2 3\n").unwrap();
+        fs::write(input_path(&dir, 2015, 25, "b"), "1\n").unwrap();
+        fs::write(
+            input_path(&dir, 2015, 25, "example").with_extension("toml"),
+            "part1 = 3\n\n[params]\nsize = 10\nname = \"AA\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(names(&dir, 2015, 25), vec!["b", "example"]);
+        assert!(names(&dir, 2015, 24).is_empty());
+
+        let fixture = Fixture::load(&dir, 2015, 25, "example").unwrap();
+        assert_eq!(fixture.input, "1 2 3\n");
+        assert_eq!(fixture.expected, [Some(String::from("3")), None]);
+        assert_eq!(
+            fixture.params,
+            vec![
+                (String::from("name"), String::from("AA")),
+                (String::from("size"), String::from("10")),
+            ]
+        );
+        assert_eq!(
+            Fixture::load(&dir, 2015, 25, "b").unwrap().expected,
+            [None, None]
+        );
+        fs::write(
+            input_path(&dir, 2015, 25, "b").with_extension("toml"),
+            "part1 = 2\n",
+        )
+        .unwrap();
+        assert_eq!(
+            check(&Fixture::load(&dir, 2015, 25, "b").unwrap()).unwrap(),
+            vec![Mismatch {
+                part: 1,
+                expected: String::from("2"),
+                actual: Some(String::from("1")),
+            }]
+        );
+        assert_eq!(
+            Fixture::load(&dir, 2015, 25, "c").unwrap_err().to_string(),
+            "Day 25 of 2015 has no example c"
+        );
+        // The registry's test solver for day 25 can't set parameters
+        assert!(check(&fixture).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
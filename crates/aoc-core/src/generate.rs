@@ -0,0 +1,173 @@
+//! Random but valid puzzle inputs, to stress solvers with more than the one
+//! real input
+//!
+//! A day implements [`InputGenerator`] and registers it with
+//! [`register_generator!`](crate::register_generator), after which `aoc gen`
+//! can write its inputs out. The same seed and size always give the same
+//! input.
+
+use std::ops::RangeInclusive;
+
+/// A small seeded pseudo-random generator (SplitMix64), kept here rather than
+/// pulled in so generated inputs never change with a dependency update
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// A generator whose numbers only depend on `seed`
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// The next number, uniformly distributed over every `u64`
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (from, to) = range.into_inner();
+        assert!(from <= to, "empty range {}..={}", from, to);
+        from + (self.next_u64() % (to - from + 1) as u64) as usize
+    }
+
+    /// A number below `n`, which must not be 0
+    pub fn below(&mut self, n: usize) -> usize {
+        self.range(0..=n - 1)
+    }
+
+    /// True once in `n` times on average
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    /// One of `items`, which must not be empty
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Puts `items` in a random order (Fisher-Yates)
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Writes syntactically valid inputs of a day, which its solver can parse and
+/// solve, for benchmarks and fuzzing
+pub trait InputGenerator {
+    /// The raw input, without a trailing newline. What `size` counts is up to
+    /// the day, e.g. packet pairs or valves, and it is clamped to what the
+    /// puzzle allows.
+    fn generate(&self, rng: &mut Rng, size: usize) -> String;
+
+    /// The size of a real input, used when none is given
+    fn real_size(&self) -> usize;
+}
+
+/// A day's generator, created by [`register_generator!`](crate::register_generator)
+pub struct Generator {
+    /// The year of the day
+    pub year: i32,
+    /// The day of the puzzle
+    pub day: u32,
+    /// Builds the generator with the day's default parameters
+    pub generator: fn() -> Box<dyn InputGenerator>,
+}
+
+inventory::collect!(Generator);
+
+/// Registers the input generator of a day, e.g.
+/// `register_generator!(2022, 13, Problem);`
+#[macro_export]
+macro_rules! register_generator {
+    ($year:expr, $day:expr, $generator:expr) => {
+        $crate::inventory::submit! {
+            $crate::generate::Generator {
+                year: $year,
+                day: $day,
+                generator: || Box::new($generator),
+            }
+        }
+    };
+}
+
+/// The generator of `day` of `year`, if it has one
+pub fn get(year: i32, day: u32) -> Option<&'static Generator> {
+    inventory::iter::<Generator>
+        .into_iter()
+        .find(|g| g.year == year && g.day == day)
+}
+
+/// Every day of `year` with a generator, in order
+pub fn days(year: i32) -> Vec<u32> {
+    let mut days: Vec<u32> = inventory::iter::<Generator>
+        .into_iter()
+        .filter(|g| g.year == year)
+        .map(|g| g.day)
+        .collect();
+    days.sort_unstable();
+    days
+}
+
+/// An input of `day` of `year` from `seed`, of the size of a real one if
+/// `size` is not given, or `None` if the day has no generator
+pub fn generate(year: i32, day: u32, seed: u64, size: Option<usize>) -> Option<String> {
+    let generator = (get(year, day)?.generator)();
+    let size = size.unwrap_or_else(|| generator.real_size());
+    Some(generator.generate(&mut Rng::new(seed), size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Numbers;
+
+    impl InputGenerator for Numbers {
+        fn generate(&self, rng: &mut Rng, size: usize) -> String {
+            let numbers: Vec<String> = (0..size).map(|_| rng.range(1..=6).to_string()).collect();
+            numbers.join("\n")
+        }
+
+        fn real_size(&self) -> usize {
+            3
+        }
+    }
+
+    crate::register_generator!(2015, 25, Numbers);
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(7);
+        let rolls: Vec<usize> = (0..1000).map(|_| rng.range(1..=6)).collect();
+        assert!(rolls.iter().all(|r| (1..=6).contains(r)));
+        assert!((1..=6).all(|n| rolls.contains(&n)));
+        assert_eq!(rng.range(4..=4), 4);
+
+        let mut items: Vec<u32> = (0..10).collect();
+        rng.shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(days(2015), vec![25]);
+        let input = generate(2015, 25, 1, None).unwrap();
+        assert_eq!(input.lines().count(), 3);
+        assert_eq!(generate(2015, 25, 1, None), Some(input));
+        assert_ne!(
+            generate(2015, 25, 1, Some(20)),
+            generate(2015, 25, 2, Some(20))
+        );
+        assert_eq!(generate(2015, 24, 1, None), None);
+    }
+}
@@ -0,0 +1,65 @@
+//! Everything needed to run Advent of Code solutions, independent of the year.
+//!
+//! A day implements [`Solver`](solver::Solver) and registers itself with
+//! [`register!`], after which the [`runner`] can load its input and solve it by
+//! year and day number, time it, benchmark it and check it against known
+//! answers and the [`fixtures`] taken from the puzzle text.
+//!
+//! ```
+//! use aoc_core::{
+//!     parse::{parse_token, ParseError},
+//!     register, registry,
+//!     solver::{Answer, Solver},
+//! };
+//!
+//! pub struct Problem;
+//!
+//! register!(2022, 1, Problem);
+//!
+//! impl Solver for Problem {
+//!     type Input<'a> = Vec<u32>;
+//!     type Prepared<'a> = ();
+//!     type Output1 = u32;
+//!     type Output2 = u32;
+//!
+//!     fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+//!         raw_input
+//!             .lines()
+//!             .map(|l| parse_token(raw_input, l, "a number"))
+//!             .collect()
+//!     }
+//!
+//!     fn solve_first(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output1> {
+//!         input.iter().max().copied()
+//!     }
+//!
+//!     fn solve_second(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output2> {
+//!         Some(input.iter().sum())
+//!     }
+//! }
+//!
+//! const EXAMPLE: &str = "3\n1\n2";
+//!
+//! let solver = (registry::get(2022, 1).unwrap().solver)();
+//! let results = solver.run(2022, 1, String::from(EXAMPLE), None).unwrap();
+//! assert_eq!(results[0].answer, Some(Answer::Integer(3)));
+//! assert_eq!(results[1].answer, Some(Answer::Integer(6)));
+//! ```
+
+#![warn(missing_docs)]
+
+pub mod answers;
+pub mod bench;
+pub mod fixtures;
+pub mod generate;
+pub mod normalize;
+pub mod ocr;
+pub mod parse;
+pub mod pool;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod solver;
+
+#[doc(hidden)]
+pub use inventory;
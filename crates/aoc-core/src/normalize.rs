@@ -0,0 +1,92 @@
+//! Canonicalising raw inputs before they are parsed, so an input saved with
+//! Windows line endings or a byte order mark parses like the original
+
+/// The steps applied to a raw input before [`Solver::parse_input`], chosen per
+/// day with [`Solver::normalization`]
+///
+/// [`Solver::parse_input`]: crate::solver::Solver::parse_input
+/// [`Solver::normalization`]: crate::solver::Solver::normalization
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalization {
+    /// Removes a leading UTF-8 byte order mark
+    pub strip_bom: bool,
+    /// Turns `\r\n` line endings into `\n`
+    pub line_endings: bool,
+    /// Removes the spaces and tabs at the end of every line
+    pub trim_lines: bool,
+    /// Removes whitespace, blank lines included, from the end of the input, so
+    /// it never ends with a newline
+    pub trim_end: bool,
+}
+
+impl Normalization {
+    /// Every step, what most days want
+    pub const ALL: Self = Self {
+        strip_bom: true,
+        line_endings: true,
+        trim_lines: true,
+        trim_end: true,
+    };
+
+    /// The input exactly as it was read
+    pub const NONE: Self = Self {
+        strip_bom: false,
+        line_endings: false,
+        trim_lines: false,
+        trim_end: false,
+    };
+
+    /// The steps that are enabled, applied to `raw_input` in order
+    pub fn apply(&self, raw_input: String) -> String {
+        let mut input = raw_input;
+        if self.strip_bom && input.starts_with('\u{feff}') {
+            input.drain(..'\u{feff}'.len_utf8());
+        }
+        if self.line_endings && input.contains('\r') {
+            input = input.replace("\r\n", "\n");
+        }
+        if self.trim_lines {
+            let lines: Vec<&str> = input
+                .split('\n')
+                .map(|l| l.trim_end_matches([' ', '\t']))
+                .collect();
+            input = lines.join("\n");
+        }
+        if self.trim_end {
+            input.truncate(input.trim_end().len());
+        }
+        input
+    }
+}
+
+impl Default for Normalization {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let windows = String::from("\u{feff}1000\r\n2000 \r\n\r\n3000\r\n\r\n");
+        assert_eq!(
+            Normalization::ALL.apply(windows.clone()),
+            "1000\n2000\n\n3000"
+        );
+        assert_eq!(Normalization::NONE.apply(windows.clone()), windows);
+
+        let keep_lines = Normalization {
+            trim_lines: false,
+            trim_end: false,
+            ..Normalization::ALL
+        };
+        assert_eq!(keep_lines.apply(windows), "1000\n2000 \n\n3000\n\n");
+        assert_eq!(
+            Normalization::ALL.apply(String::from("mjqjpqm\n")),
+            "mjqjpqm"
+        );
+    }
+}
@@ -0,0 +1,145 @@
+//! Reading the block letters some puzzles draw as their answer
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// Width of a letter, they are separated by one blank column
+const WIDTH: usize = 4;
+const HEIGHT: usize = 6;
+
+/// The 4×6 font, with the letters seen in puzzles so far
+const FONT: [(char, &str); 16] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// A letter that is not in the font, or a picture that is not letters at all
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OcrError {
+    /// Position of the letter, starting at 0
+    pub index: usize,
+    /// The letter as drawn, with `#` and `.`
+    pub rendering: String,
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "unknown letter {}:\n{}", self.index + 1, self.rendering)
+    }
+}
+
+impl Error for OcrError {}
+
+/// Reads the letters drawn in `rows`, where `on` marks the lit cells and
+/// anything else is blank
+pub fn read(rows: &str, on: char) -> Result<String, OcrError> {
+    let rows: Vec<Vec<bool>> = rows
+        .lines()
+        .map(|l| l.chars().map(|c| c == on).collect())
+        .collect();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let cell = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+
+    (0..width.div_ceil(WIDTH + 1))
+        .map(|index| {
+            let x0 = index * (WIDTH + 1);
+            let glyph: String = (0..HEIGHT * WIDTH)
+                .map(|i| {
+                    let (x, y) = (x0 + i % WIDTH, i / WIDTH);
+                    match y < rows.len() && cell(x, y) {
+                        true => '#',
+                        false => '.',
+                    }
+                })
+                .collect();
+
+            // Letters touching their neighbours are not from the font either
+            let separated = (0..rows.len()).all(|y| !cell(x0 + WIDTH, y));
+            let known = rows.len() == HEIGHT && separated;
+            FONT.iter()
+                .find(|(_, g)| known && glyph == *g)
+                .map(|(letter, _)| *letter)
+                .ok_or_else(|| OcrError {
+                    index,
+                    rendering: render(&glyph),
+                })
+        })
+        .collect()
+}
+
+/// The letters of the font, in alphabetical order
+pub fn letters() -> impl Iterator<Item = char> {
+    FONT.iter().map(|(letter, _)| *letter)
+}
+
+/// Draws `text` with `#` and `.` the way puzzles do, or returns `None` if one
+/// of its letters is not in the font
+pub fn draw(text: &str) -> Option<String> {
+    let glyphs: Vec<&str> = text
+        .chars()
+        .map(|c| FONT.iter().find(|(l, _)| *l == c).map(|(_, glyph)| *glyph))
+        .collect::<Option<_>>()?;
+    let rows: Vec<String> = (0..HEIGHT)
+        .map(|y| {
+            let row: Vec<&str> = glyphs
+                .iter()
+                .map(|glyph| &glyph[y * WIDTH..(y + 1) * WIDTH])
+                .collect();
+            row.join(".")
+        })
+        .collect();
+    Some(rows.join("\n"))
+}
+
+fn render(glyph: &str) -> String {
+    let rows: Vec<&str> = (0..HEIGHT)
+        .map(|y| &glyph[y * WIDTH..(y + 1) * WIDTH])
+        .collect();
+    rows.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read() {
+        let screen = draw("EHZFZHCZ").unwrap();
+        assert_eq!(read(&screen, '#'), Ok(String::from("EHZFZHCZ")));
+        let screen = draw("RGZEHURK")
+            .unwrap()
+            .replace('#', "█")
+            .replace('.', " ");
+        assert_eq!(read(&screen, '█'), Ok(String::from("RGZEHURK")));
+        assert_eq!(draw("EHQ"), None);
+        assert_eq!(letters().count(), FONT.len());
+    }
+
+    #[test]
+    fn test_unknown_letter() {
+        let mut screen = draw("ABC").unwrap();
+        // Light the top left corner of the C
+        screen.replace_range(10..11, "#");
+        let error = read(&screen, '#').unwrap_err();
+
+        assert_eq!(error.index, 2);
+        assert_eq!(error.rendering, "###.\n#..#\n#...\n#...\n#..#\n.##.");
+        assert!(error.to_string().starts_with("unknown letter 3:\n###."));
+    }
+}
@@ -0,0 +1,123 @@
+//! Input parse errors that point at the offending text
+
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// Where and why an input could not be parsed, displayed with the line it
+/// happened on like a compiler error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Set by the runner, 0 until then
+    pub day: u32,
+    /// 1-based line of the input
+    pub line: usize,
+    /// 1-based column, in characters
+    pub column: usize,
+    /// The offending text
+    pub text: String,
+    /// What should have been there, e.g. `a number`
+    pub expected: String,
+    source_line: String,
+}
+
+impl ParseError {
+    /// Builds an error pointing at `text`, which must be a slice of `input`.
+    /// Slices from elsewhere are reported at the end of the input.
+    pub fn at(input: &str, text: &str, expected: impl Into<String>) -> Self {
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&o| o + text.len() <= input.len() && input.is_char_boundary(o))
+            .unwrap_or(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        Self {
+            day: 0,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: text.to_string(),
+            expected: expected.into(),
+            source_line: input[line_start..line_end].to_string(),
+        }
+    }
+
+    /// Builds an error for an input that ended while `expected` was still
+    /// missing
+    pub fn end_of_input(input: &str, expected: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], expected)
+    }
+}
+
+/// Parses `text`, a slice of `input`, reporting where it is on failure
+pub fn parse_token<T: FromStr>(input: &str, text: &str, expected: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::at(input, text, expected))
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let found = match self.text.lines().next() {
+            Some(text) if !text.is_empty() => format!("`{}`", text),
+            _ => String::from("end of line"),
+        };
+        let gutter = " ".repeat(self.line.to_string().len());
+        let underline = self
+            .text
+            .lines()
+            .next()
+            .map_or(1, |t| t.chars().count().max(1));
+
+        writeln!(f, "expected {}, found {}", self.expected, found)?;
+        writeln!(
+            f,
+            "{}--> day{}:{}:{}",
+            gutter, self.day, self.line, self.column
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(underline)
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "2-4,6-8\n2-x,4-5\n";
+
+    #[test]
+    fn test_position() {
+        let token = &INPUT[10..11];
+        let error = ParseError::at(INPUT, token, "a number");
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "x");
+
+        let error = ParseError::end_of_input(INPUT, "a number");
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+
+    #[test]
+    fn test_display() {
+        let mut error = parse_token::<u32>(INPUT, &INPUT[10..11], "a number").unwrap_err();
+        error.day = 4;
+        let expected = r#"expected a number, found `x`
+ --> day4:2:3
+  |
+2 | 2-x,4-5
+  |   ^"#;
+        assert_eq!(error.to_string(), expected);
+    }
+}
@@ -0,0 +1,332 @@
+//! Self-registration of solvers, so the runner can find every day by number
+
+use std::{
+    hint::black_box,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    bench::{measure, BenchOptions, BenchResult, Stage},
+    parse::ParseError,
+    solver::{panic_message, Answer, Failure, PartResult, Solver},
+};
+
+/// Type-erased [`Solver`] with its answers converted to [`Answer`]s, so days
+/// with different `Input`/`Output` types can live in the same registry
+pub trait DynSolver {
+    /// See [`Solver::set_param`]
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String>;
+
+    /// Normalizes, parses and prepares `raw_input` and solves both parts, or
+    /// only `part` if given. `on_start` gets each part just before it is
+    /// solved and `on_part` its result as soon as it is there. A part that
+    /// panics gets a [`Failure`] and the next one still runs.
+    fn run_each(
+        &self,
+        year: i32,
+        day: u32,
+        raw_input: String,
+        part: Option<u8>,
+        on_start: &mut dyn FnMut(u8),
+        on_part: &mut dyn FnMut(PartResult),
+    ) -> Result<(), ParseError>;
+
+    /// See [`DynSolver::run_each`], with the results of every part at once
+    fn run(
+        &self,
+        year: i32,
+        day: u32,
+        raw_input: String,
+        part: Option<u8>,
+    ) -> Result<Vec<PartResult>, ParseError> {
+        let mut results = Vec::new();
+        self.run_each(year, day, raw_input, part, &mut |_| (), &mut |r| {
+            results.push(r)
+        })?;
+        Ok(results)
+    }
+
+    /// Benchmarks parsing and each part separately
+    fn bench(
+        &self,
+        day: u32,
+        raw_input: &str,
+        part: Option<u8>,
+        options: &BenchOptions,
+    ) -> Result<Vec<BenchResult>, ParseError>;
+}
+
+/// Runs a timed part, turning a panic into a [`Failure`]
+fn isolated<O: Into<Answer>>(
+    solve: impl FnOnce() -> (Option<O>, Duration),
+) -> (Option<Answer>, Option<Failure>, Duration) {
+    let before = Instant::now();
+    match panic::catch_unwind(AssertUnwindSafe(solve)) {
+        Ok((answer, solve_time)) => (answer.map(Into::into), None, solve_time),
+        Err(payload) => (
+            None,
+            Some(Failure::Panic(panic_message(payload))),
+            before.elapsed(),
+        ),
+    }
+}
+
+impl<S: Solver> DynSolver for S {
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        Solver::set_param(self, name, value)
+    }
+
+    fn run_each(
+        &self,
+        year: i32,
+        day: u32,
+        raw_input: String,
+        part: Option<u8>,
+        on_start: &mut dyn FnMut(u8),
+        on_part: &mut dyn FnMut(PartResult),
+    ) -> Result<(), ParseError> {
+        // Not part of the parse time, it is the same work for every day. The
+        // input may borrow from it, so it lives until both parts are done.
+        let raw_input = self.normalization().apply(raw_input);
+        let before = Instant::now();
+        let input = self.parse_input(&raw_input).map_err(|mut e| {
+            e.day = day;
+            e
+        })?;
+        let parse_time = before.elapsed();
+        let (prepared, prepare_time) = self.timed_prepare(&input);
+
+        let result = |part, (answer, failure, solve_time)| PartResult {
+            year,
+            day,
+            part,
+            answer,
+            failure,
+            parse_time,
+            prepare_time,
+            solve_time,
+        };
+        if part != Some(2) {
+            on_start(1);
+            on_part(result(1, isolated(|| self.timed_first(&input, &prepared))));
+        }
+        if part != Some(1) {
+            on_start(2);
+            on_part(result(2, isolated(|| self.timed_second(&input, &prepared))));
+        }
+        Ok(())
+    }
+
+    fn bench(
+        &self,
+        day: u32,
+        raw_input: &str,
+        part: Option<u8>,
+        options: &BenchOptions,
+    ) -> Result<Vec<BenchResult>, ParseError> {
+        let raw_input = &self.normalization().apply(raw_input.to_string());
+        let input = self.parse_input(raw_input).map_err(|mut e| {
+            e.day = day;
+            e
+        })?;
+        let prepared = self.prepare(&input);
+
+        let mut results = vec![BenchResult {
+            day,
+            stage: Stage::Parse,
+            stats: measure(options, || (), |_| self.parse_input(black_box(raw_input))),
+        }];
+        results.push(BenchResult {
+            day,
+            stage: Stage::Prepare,
+            stats: measure(options, || (), |_| self.prepare(black_box(&input))),
+        });
+        if part != Some(2) {
+            results.push(BenchResult {
+                day,
+                stage: Stage::Part(1),
+                stats: measure(
+                    options,
+                    || (),
+                    |_| self.solve_first(black_box(&input), black_box(&prepared)),
+                ),
+            });
+        }
+        if part != Some(1) {
+            results.push(BenchResult {
+                day,
+                stage: Stage::Part(2),
+                stats: measure(
+                    options,
+                    || (),
+                    |_| self.solve_second(black_box(&input), black_box(&prepared)),
+                ),
+            });
+        }
+        Ok(results)
+    }
+}
+
+/// The name of the implementation the runner uses, registered without a name
+pub const MAIN: &str = "main";
+
+/// A day's entry in the registry, created by [`register!`](crate::register)
+pub struct Registration {
+    /// The year of the day
+    pub year: i32,
+    /// The day of the puzzle
+    pub day: u32,
+    /// Tells the implementations of a day apart, [`MAIN`] for the one in use
+    pub name: &'static str,
+    /// Builds the solver with its default parameters
+    pub solver: fn() -> Box<dyn DynSolver>,
+}
+
+inventory::collect!(Registration);
+
+/// Registers a solver for a day of a year, e.g. `register!(2022, 1, Problem);`
+/// or `register!(2022, 15, Problem::default());`. Its examples live in
+/// [`fixtures`](crate::fixtures).
+///
+/// Other implementations of the same day, like a slow but obviously correct
+/// one, are registered with a name, e.g. `register!(2022, 15, "naive",
+/// Naive::default());`, to be crosschecked against the main one.
+#[macro_export]
+macro_rules! register {
+    ($year:expr, $day:expr, $solver:expr) => {
+        $crate::register!($year, $day, $crate::registry::MAIN, $solver);
+    };
+    ($year:expr, $day:expr, $name:expr, $solver:expr) => {
+        $crate::inventory::submit! {
+            $crate::registry::Registration {
+                year: $year,
+                day: $day,
+                name: $name,
+                solver: || Box::new($solver),
+            }
+        }
+    };
+}
+
+/// The main implementation of `day` of `year`, if it has been solved
+pub fn get(year: i32, day: u32) -> Option<&'static Registration> {
+    inventory::iter::<Registration>
+        .into_iter()
+        .find(|r| r.year == year && r.day == day && r.name == MAIN)
+}
+
+/// Every implementation of `day` of `year`, the main one first and the others
+/// by name
+pub fn implementations(year: i32, day: u32) -> Vec<&'static Registration> {
+    let mut implementations: Vec<&Registration> = inventory::iter::<Registration>
+        .into_iter()
+        .filter(|r| r.year == year && r.day == day)
+        .collect();
+    implementations.sort_unstable_by_key(|r| (r.name != MAIN, r.name));
+    implementations
+}
+
+/// Every registered day of `year`, in order
+pub fn days(year: i32) -> Vec<u32> {
+    let mut days: Vec<u32> = inventory::iter::<Registration>
+        .into_iter()
+        .filter(|r| r.year == year)
+        .map(|r| r.day)
+        .collect();
+    days.sort_unstable();
+    days.dedup();
+    days
+}
+
+/// Every year with at least one registered day, in order
+pub fn years() -> Vec<i32> {
+    let mut years: Vec<i32> = inventory::iter::<Registration>
+        .into_iter()
+        .map(|r| r.year)
+        .collect();
+    years.sort_unstable();
+    years.dedup();
+    years
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::Answer;
+
+    struct Count;
+
+    impl Solver for Count {
+        type Input<'a> = Vec<u32>;
+        /// The sum, which only the second part needs
+        type Prepared<'a> = u32;
+        type Output1 = usize;
+        type Output2 = usize;
+
+        fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+            raw_input
+                .split_whitespace()
+                .map(|n| crate::parse::parse_token(raw_input, n, "a number"))
+                .collect()
+        }
+
+        fn prepare<'a>(&self, input: &Self::Input<'a>) -> Self::Prepared<'a> {
+            input.iter().sum()
+        }
+
+        fn solve_first(&self, input: &Self::Input<'_>, _: &u32) -> Option<Self::Output1> {
+            Some(input.len())
+        }
+
+        fn solve_second(&self, _input: &Self::Input<'_>, sum: &u32) -> Option<Self::Output2> {
+            (*sum > 0).then_some(*sum as usize)
+        }
+    }
+
+    crate::register!(2015, 25, Count);
+    crate::register!(2015, 25, "again", Count);
+
+    #[test]
+    fn test_days() {
+        assert_eq!(years(), vec![2015]);
+        // Days 22 and 24 are the runner's test solvers
+        assert_eq!(days(2015), vec![22, 24, 25]);
+        assert!(days(2016).is_empty());
+        assert!(get(2015, 23).is_none());
+        assert!(get(2016, 25).is_none());
+
+        let names: Vec<_> = implementations(2015, 25).iter().map(|r| r.name).collect();
+        assert_eq!(names, vec!["main", "again"]);
+        assert_eq!(get(2015, 25).unwrap().name, "main");
+        assert!(implementations(2015, 23).is_empty());
+    }
+
+    #[test]
+    fn test_run() {
+        let registration = get(2015, 25).unwrap();
+        let results = (registration.solver)()
+            .run(2015, 25, String::from("1 2 3"), None)
+            .unwrap();
+        let answers: Vec<_> = results.into_iter().map(|r| (r.part, r.answer)).collect();
+        assert_eq!(
+            answers,
+            vec![(1, Some(Answer::Integer(3))), (2, Some(Answer::Integer(6)))]
+        );
+        let results = (registration.solver)()
+            .run(2015, 25, String::from("0"), Some(2))
+            .unwrap();
+        assert_eq!((results[0].part, &results[0].answer), (2, &None));
+
+        let error = (registration.solver)()
+            .run(2015, 25, String::from("1 x"), None)
+            .unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (25, 1, 3));
+
+        // Saved on Windows, with a byte order mark
+        let results = (registration.solver)()
+            .run(2015, 25, String::from("\u{feff}1\r\n2\r\n"), Some(1))
+            .unwrap();
+        assert_eq!(results[0].answer, Some(Answer::Integer(2)));
+    }
+}
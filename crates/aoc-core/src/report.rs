@@ -0,0 +1,587 @@
+//! Tables and structured output of runs, checks and benchmarks
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use serde::{Serialize, Serializer};
+
+use crate::{
+    answers::Check,
+    bench::BenchResult,
+    solver::{Answer, Failure, PartResult},
+};
+
+/// How a run is printed, chosen with `--format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// A [`summary_table`]
+    Text,
+    /// An array of [`Record`]s
+    Json,
+    /// One [`Record`] per line, with a header
+    Csv,
+}
+
+/// The outcome of a part in the structured formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordStatus {
+    /// The solver returned an answer
+    Ok,
+    /// The solver ran but returned no answer
+    NoAnswer,
+    /// The day could not be run, e.g. a missing input or a parse error
+    Error,
+    /// The part panicked
+    Panic,
+    /// The part ran past its timeout
+    Timeout,
+    /// Parsing and preparing the input ran past the timeout
+    InputTimeout,
+    /// The part was not run because an earlier one timed out
+    Skipped,
+}
+
+impl RecordStatus {
+    /// The status as written in JSON and CSV
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordStatus::Ok => "ok",
+            RecordStatus::NoAnswer => "no_answer",
+            RecordStatus::Error => "error",
+            RecordStatus::Panic => "panic",
+            RecordStatus::Timeout => "timeout",
+            RecordStatus::InputTimeout => "input_timeout",
+            RecordStatus::Skipped => "skipped",
+        }
+    }
+}
+
+impl Serialize for RecordStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// One day/part of a run, flattened for the structured formats
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    /// The year of the day
+    pub year: i32,
+    /// The day of the puzzle
+    pub day: u32,
+    /// 1 or 2
+    pub part: u8,
+    /// How the part went
+    pub status: RecordStatus,
+    /// The answer, as displayed
+    pub answer: Option<String>,
+    /// Nanoseconds spent parsing, if the part ran without failing
+    pub parse_ns: Option<u64>,
+    /// Nanoseconds spent in `prepare`, if the part ran without failing
+    pub prepare_ns: Option<u64>,
+    /// Nanoseconds spent solving the part, if it ran without failing
+    pub solve_ns: Option<u64>,
+    /// Where the input came from
+    pub input: String,
+    /// Why there is no answer, if there is an error or failure
+    pub error: Option<String>,
+}
+
+impl Record {
+    /// The record of a part that ran on `input`
+    pub fn new(result: &PartResult, input: &str) -> Self {
+        // Failed and skipped parts have no timings worth keeping
+        let ns = |time: Duration| result.failure.is_none().then_some(time.as_nanos() as u64);
+        Self {
+            year: result.year,
+            day: result.day,
+            part: result.part,
+            status: match (&result.answer, &result.failure) {
+                (_, Some(Failure::Panic(_))) => RecordStatus::Panic,
+                (_, Some(Failure::Timeout(_))) => RecordStatus::Timeout,
+                (_, Some(Failure::InputTimeout(_))) => RecordStatus::InputTimeout,
+                (_, Some(Failure::Skipped)) => RecordStatus::Skipped,
+                (Some(_), None) => RecordStatus::Ok,
+                (None, None) => RecordStatus::NoAnswer,
+            },
+            answer: result.answer.as_ref().map(Answer::to_string),
+            parse_ns: ns(result.parse_time),
+            prepare_ns: ns(result.prepare_time),
+            solve_ns: ns(result.solve_time),
+            input: input.to_string(),
+            error: result.failure.as_ref().map(Failure::to_string),
+        }
+    }
+
+    /// The record of a part that could not run because of `error`
+    pub fn error(year: i32, day: u32, part: u8, input: &str, error: &str) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            status: RecordStatus::Error,
+            answer: None,
+            parse_ns: None,
+            prepare_ns: None,
+            solve_ns: None,
+            input: input.to_string(),
+            error: Some(error.to_string()),
+        }
+    }
+}
+
+/// The records as a pretty-printed JSON array
+pub fn json(records: &[Record]) -> String {
+    serde_json::to_string_pretty(records).unwrap() + "\n"
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// The records as CSV, quoting fields where needed
+pub fn csv(records: &[Record]) -> String {
+    let mut csv =
+        String::from("year,day,part,status,answer,parse_ns,prepare_ns,solve_ns,input,error\n");
+    for r in records {
+        let fields = [
+            r.year.to_string(),
+            r.day.to_string(),
+            r.part.to_string(),
+            r.status.as_str().to_string(),
+            r.answer.clone().unwrap_or_default(),
+            r.parse_ns.map(|ns| ns.to_string()).unwrap_or_default(),
+            r.prepare_ns.map(|ns| ns.to_string()).unwrap_or_default(),
+            r.solve_ns.map(|ns| ns.to_string()).unwrap_or_default(),
+            r.input.clone(),
+            r.error.clone().unwrap_or_default(),
+        ];
+        let line: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        writeln!(csv, "{}", line.join(",")).unwrap();
+    }
+    csv
+}
+
+fn column_width<'a>(header: &str, values: impl Iterator<Item = &'a String>) -> usize {
+    values
+        .map(|v| v.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(header.len())
+}
+
+/// A table cell for an expected answer, which may be a multi-line grid
+fn expected_cell(expected: &str) -> String {
+    match expected.contains('\n') {
+        true => Answer::Grid {
+            rows: expected.to_string(),
+            note: None,
+        }
+        .summary(),
+        false => expected.to_string(),
+    }
+}
+
+/// The cell of a part's answer, or of why it has none
+fn answer_cell(answer: Option<&Answer>, failure: Option<&Failure>) -> String {
+    match (answer, failure) {
+        (_, Some(failure)) => failure.summary().to_string(),
+        (Some(answer), None) => answer.summary(),
+        (None, None) => String::from("-"),
+    }
+}
+
+/// The answers and timings of a run, with any grid answers and failures
+/// described below it, each grid followed by its note if it has one
+pub fn summary_table(results: &[PartResult]) -> String {
+    let answers: Vec<String> = results
+        .iter()
+        .map(|r| answer_cell(r.answer.as_ref(), r.failure.as_ref()))
+        .collect();
+    let width = column_width("Answer", answers.iter());
+
+    let mut table = String::new();
+    writeln!(
+        table,
+        "{:>3}  {:>4}  {:<width$}  {:>12}  {:>12}  {:>12}",
+        "Day", "Part", "Answer", "Parse", "Prepare", "Solve"
+    )
+    .unwrap();
+    for (r, answer) in results.iter().zip(&answers) {
+        writeln!(
+            table,
+            "{:>3}  {:>4}  {:<width$}  {:>12}  {:>12}  {:>12}",
+            r.day,
+            r.part,
+            answer,
+            format!("{:.2?}", r.parse_time),
+            format!("{:.2?}", r.prepare_time),
+            format!("{:.2?}", r.solve_time),
+        )
+        .unwrap();
+    }
+    for r in results {
+        if let Some(Answer::Grid { rows, note }) = &r.answer {
+            write!(table, "\nDay {} part {}:\n{}\n", r.day, r.part, rows).unwrap();
+            if let Some(note) = note {
+                writeln!(table, "{}", note).unwrap();
+            }
+        }
+        if let Some(failure) = &r.failure {
+            write!(table, "\nDay {} part {} {}\n", r.day, r.part, failure).unwrap();
+        }
+    }
+    table
+}
+
+/// Every checked part with its answer, its expected one and whether they
+/// match
+pub fn verify_table(checks: &[Check]) -> String {
+    let answers: Vec<String> = checks
+        .iter()
+        .map(|c| answer_cell(c.answer.as_ref(), None))
+        .collect();
+    let width = column_width("Answer", answers.iter());
+
+    let mut table = String::new();
+    writeln!(
+        table,
+        "{:>4}  {:>3}  {:>4}  {:<7}  {:<width$}  Expected",
+        "Year", "Day", "Part", "Status", "Answer"
+    )
+    .unwrap();
+    for (c, answer) in checks.iter().zip(&answers) {
+        writeln!(
+            table,
+            "{:>4}  {:>3}  {:>4}  {:<7}  {:<width$}  {}",
+            c.year,
+            c.day,
+            c.part,
+            c.status,
+            answer,
+            c.expected
+                .as_deref()
+                .map_or(String::from("-"), expected_cell),
+        )
+        .unwrap();
+    }
+    table
+}
+
+/// How a day went on one team member's input, for [`matrix_table`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatrixCell {
+    /// The member has no input for the day
+    NoInput,
+    /// The input could not be read or parsed
+    Error(String),
+    /// The results of both parts
+    Solved(Vec<PartResult>),
+}
+
+impl MatrixCell {
+    /// The answer to `part` with its total time, or why there is none
+    fn text(&self, part: u8) -> String {
+        let result = match self {
+            MatrixCell::NoInput => return String::from("-"),
+            MatrixCell::Error(_) => return String::from("ERROR"),
+            MatrixCell::Solved(results) => results.iter().find(|r| r.part == part),
+        };
+        match result {
+            Some(r) if r.failure.is_none() => format!(
+                "{} ({:.2?})",
+                answer_cell(r.answer.as_ref(), None),
+                r.parse_time + r.prepare_time + r.solve_time
+            ),
+            Some(r) => answer_cell(None, r.failure.as_ref()),
+            None => String::from("-"),
+        }
+    }
+}
+
+/// A grid of the answers every member's input gives for each day and part,
+/// with the errors and failures described below it
+pub fn matrix_table(members: &[String], days: &[(u32, Vec<MatrixCell>)]) -> String {
+    let rows: Vec<(u32, u8, Vec<String>)> = days
+        .iter()
+        .flat_map(|(day, cells)| {
+            (1..=2).map(move |part| (*day, part, cells.iter().map(|c| c.text(part)).collect()))
+        })
+        .collect();
+    let widths: Vec<usize> = members
+        .iter()
+        .enumerate()
+        .map(|(i, member)| column_width(member, rows.iter().map(|(_, _, cells)| &cells[i])))
+        .collect();
+
+    let line = |first: String, cells: &[String]| {
+        let mut line = first;
+        for (cell, width) in cells.iter().zip(&widths) {
+            write!(line, "  {:<width$}", cell).unwrap();
+        }
+        line.trim_end().to_string() + "\n"
+    };
+    let mut table = line(String::from("Day  Part"), members);
+    for (day, part, cells) in &rows {
+        table.push_str(&line(format!("{:>3}  {:>4}", day, part), cells));
+    }
+
+    for (day, cells) in days {
+        for (member, cell) in members.iter().zip(cells) {
+            match cell {
+                MatrixCell::Error(e) => write!(table, "\nDay {} {}: {}\n", day, member, e),
+                MatrixCell::Solved(results) => results
+                    .iter()
+                    .filter_map(|r| Some((r.part, r.failure.as_ref()?)))
+                    .try_for_each(|(part, failure)| {
+                        write!(
+                            table,
+                            "\nDay {} part {} {}: {}\n",
+                            day, part, member, failure
+                        )
+                    }),
+                MatrixCell::NoInput => Ok(()),
+            }
+            .unwrap();
+        }
+    }
+    table
+}
+
+/// The statistics of every benchmarked stage, one per line
+pub fn bench_table(results: &[BenchResult]) -> String {
+    let mut table = String::new();
+    writeln!(
+        table,
+        "{:>3}  {:<7}  {:>12}  {:>12}  {:>12}  {:>12}",
+        "Day", "Stage", "Min", "Median", "Mean", "Stddev"
+    )
+    .unwrap();
+    for r in results {
+        writeln!(
+            table,
+            "{:>3}  {:<7}  {:>12}  {:>12}  {:>12}  {:>12}",
+            r.day,
+            r.stage,
+            format!("{:.2?}", r.stats.min),
+            format!("{:.2?}", r.stats.median),
+            format!("{:.2?}", r.stats.mean),
+            format!("{:.2?}", r.stats.stddev),
+        )
+        .unwrap();
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::Status;
+
+    #[test]
+    fn test_summary_table() {
+        let results = vec![
+            PartResult {
+                year: 2022,
+                day: 1,
+                part: 1,
+                answer: Some(Answer::Integer(24000)),
+                failure: None,
+                parse_time: Duration::from_micros(15),
+                prepare_time: Duration::ZERO,
+                solve_time: Duration::from_millis(2),
+            },
+            PartResult {
+                year: 2022,
+                day: 1,
+                part: 2,
+                answer: None,
+                failure: None,
+                parse_time: Duration::from_micros(15),
+                prepare_time: Duration::ZERO,
+                solve_time: Duration::from_nanos(300),
+            },
+        ];
+        let expected = r#"Day  Part  Answer         Parse       Prepare         Solve
+  1     1  24000        15.00µs        0.00ns        2.00ms
+  1     2  -            15.00µs        0.00ns      300.00ns
+"#;
+        assert_eq!(summary_table(&results), expected);
+    }
+
+    #[test]
+    fn test_grid_answer() {
+        let results = vec![PartResult {
+            year: 2022,
+            day: 10,
+            part: 2,
+            answer: Some(Answer::Grid {
+                rows: String::from("#.#\n.#."),
+                note: Some(String::from("Not read as letters")),
+            }),
+            failure: None,
+            parse_time: Duration::from_micros(15),
+            prepare_time: Duration::ZERO,
+            solve_time: Duration::from_micros(40),
+        }];
+        let expected = r#"Day  Part  Answer             Parse       Prepare         Solve
+ 10     2  <3x2 grid>       15.00µs        0.00ns       40.00µs
+
+Day 10 part 2:
+#.#
+.#.
+Not read as letters
+"#;
+        assert_eq!(summary_table(&results), expected);
+        let without_note = PartResult {
+            answer: Some(Answer::Grid {
+                rows: String::from("#.#\n.#."),
+                note: None,
+            }),
+            ..results[0].clone()
+        };
+        assert!(summary_table(&[without_note]).ends_with("#.#\n.#.\n"));
+        assert_eq!(
+            Record::new(&results[0], "-").answer.as_deref(),
+            Some("#.#\n.#.")
+        );
+    }
+
+    #[test]
+    fn test_failures() {
+        let failed = |part, failure| PartResult {
+            year: 2022,
+            day: 16,
+            part,
+            answer: None,
+            failure: Some(failure),
+            parse_time: Duration::ZERO,
+            prepare_time: Duration::ZERO,
+            solve_time: Duration::from_millis(2),
+        };
+        let results = vec![
+            failed(1, Failure::Panic(String::from("no valve AA"))),
+            failed(2, Failure::Timeout(Duration::from_secs(10))),
+        ];
+        let expected = r#"Day  Part  Answer          Parse       Prepare         Solve
+ 16     1  PANIC          0.00ns        0.00ns        2.00ms
+ 16     2  TIMEOUT        0.00ns        0.00ns        2.00ms
+
+Day 16 part 1 panicked: no valve AA
+
+Day 16 part 2 timed out after 10.00s
+"#;
+        assert_eq!(summary_table(&results), expected);
+
+        let record = Record::new(&results[1], "inputs/2022/day16.txt");
+        assert_eq!(record.status, RecordStatus::Timeout);
+        assert_eq!(record.error.as_deref(), Some("timed out after 10.00s"));
+        assert_eq!(
+            (record.parse_ns, record.prepare_ns, record.solve_ns),
+            (None, None, None)
+        );
+        assert!(csv(&[record]).contains(",timeout,,,,,inputs/2022/day16.txt,"));
+    }
+
+    #[test]
+    fn test_matrix_table() {
+        let result = |part, answer: Option<i128>, failure| PartResult {
+            year: 2022,
+            day: 12,
+            part,
+            answer: answer.map(Answer::Integer),
+            failure,
+            parse_time: Duration::from_micros(10),
+            prepare_time: Duration::ZERO,
+            solve_time: Duration::from_micros(5),
+        };
+        let members = vec![
+            String::from("alice"),
+            String::from("bob"),
+            String::from("carol"),
+        ];
+        let days = vec![(
+            12,
+            vec![
+                MatrixCell::Solved(vec![result(1, Some(31), None), result(2, Some(29), None)]),
+                MatrixCell::Solved(vec![
+                    result(1, Some(423), None),
+                    result(2, None, Some(Failure::Panic(String::from("no start")))),
+                ]),
+                MatrixCell::NoInput,
+            ],
+        )];
+        let expected = r#"Day  Part  alice         bob            carol
+ 12     1  31 (15.00µs)  423 (15.00µs)  -
+ 12     2  29 (15.00µs)  PANIC          -
+
+Day 12 part 2 bob: panicked: no start
+"#;
+        assert_eq!(matrix_table(&members, &days), expected);
+    }
+
+    #[test]
+    fn test_verify_table() {
+        let checks = vec![
+            Check {
+                year: 2022,
+                day: 1,
+                part: 1,
+                status: Status::Pass,
+                answer: Some(Answer::Integer(24000)),
+                expected: Some(String::from("24000")),
+            },
+            Check {
+                year: 2022,
+                day: 1,
+                part: 2,
+                status: Status::Missing,
+                answer: Some(Answer::Integer(45000)),
+                expected: None,
+            },
+        ];
+        let expected = r#"Year  Day  Part  Status   Answer  Expected
+2022    1     1  pass     24000   24000
+2022    1     2  missing  45000   -
+"#;
+        assert_eq!(verify_table(&checks), expected);
+    }
+
+    #[test]
+    fn test_structured_formats() {
+        let result = PartResult {
+            year: 2022,
+            day: 5,
+            part: 1,
+            answer: Some(Answer::from("CMZ")),
+            failure: None,
+            parse_time: Duration::from_nanos(1500),
+            prepare_time: Duration::from_nanos(700),
+            solve_time: Duration::from_nanos(200),
+        };
+        let records = vec![
+            Record::new(&result, "inputs/2022/day5.txt"),
+            Record::error(
+                2022,
+                6,
+                1,
+                "inputs/2022/day6.txt",
+                "expected a number, found `x`",
+            ),
+        ];
+
+        let expected = r#"year,day,part,status,answer,parse_ns,prepare_ns,solve_ns,input,error
+2022,5,1,ok,CMZ,1500,700,200,inputs/2022/day5.txt,
+2022,6,1,error,,,,,inputs/2022/day6.txt,"expected a number, found `x`"
+"#;
+        assert_eq!(csv(&records), expected);
+
+        let parsed: serde_json::Value = serde_json::from_str(&json(&records)).unwrap();
+        assert_eq!(parsed[0]["solve_ns"], 200);
+        assert_eq!(parsed[1]["status"], "error");
+    }
+}
@@ -0,0 +1,549 @@
+//! Loading inputs and running registered solvers on them
+
+use crate::bench::{BenchOptions, BenchResult};
+use crate::fixtures::{self, Fixture, EXAMPLES_DIR};
+use crate::registry::{self, DynSolver, Registration};
+use crate::solver::{panic_message, Answer, Failure, PartResult};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::Mutex;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Where the input of a day is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `inputs/YYYY/dayN.txt`
+    Default,
+    /// A file given with `--input`
+    File(String),
+    /// Standard input, with `--stdin`
+    Stdin,
+    /// The fixture `examples/YYYY/dayN/<name>.txt`, see [`fixtures`]
+    Example(String),
+}
+
+impl InputSource {
+    /// Where the input of `day` of `year` comes from, for reports
+    pub fn describe(&self, year: i32, day: u32) -> String {
+        match self {
+            InputSource::Default => input_path(year, day),
+            InputSource::File(path) => path.clone(),
+            InputSource::Stdin => String::from("<stdin>"),
+            InputSource::Example(name) => {
+                fixtures::input_path(Path::new(EXAMPLES_DIR), year, day, name)
+                    .display()
+                    .to_string()
+            }
+        }
+    }
+}
+
+/// Where your inputs and those of other team members live
+pub const INPUTS_DIR: &str = "inputs";
+
+/// The path of your input of `day` of `year`
+pub fn input_path(year: i32, day: u32) -> String {
+    format!("{}/{}/day{}.txt", INPUTS_DIR, year, day)
+}
+
+/// The input of a team member, kept like your own one directory down
+pub fn member_input_path(member: &str, year: i32, day: u32) -> String {
+    format!("{}/{}/{}/day{}.txt", INPUTS_DIR, member, year, day)
+}
+
+/// The team members in `dir` with inputs of `year`, i.e. the directories with
+/// a `YYYY` directory inside, in order
+pub fn members(dir: &Path, year: i32) -> Vec<String> {
+    let mut members: Vec<String> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            path.join(year.to_string()).is_dir().then_some(())?;
+            Some(path.file_name()?.to_str()?.to_string())
+        })
+        .collect();
+    members.sort_unstable();
+    members
+}
+
+fn read_file(path: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+    Ok(fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?)
+}
+
+/// An input with the parameters it needs, which only examples have
+type LoadedInput = (String, Vec<(String, String)>);
+
+fn load_input(
+    year: i32,
+    day: u32,
+    source: &InputSource,
+) -> Result<LoadedInput, Box<dyn Error + Send + Sync>> {
+    match source {
+        InputSource::Default => Ok((read_file(&input_path(year, day))?, Vec::new())),
+        InputSource::File(path) => Ok((read_file(path)?, Vec::new())),
+        InputSource::Stdin => {
+            let mut raw_input = String::new();
+            io::stdin().read_to_string(&mut raw_input)?;
+            Ok((raw_input, Vec::new()))
+        }
+        InputSource::Example(name) => {
+            let fixture = Fixture::load(Path::new(EXAMPLES_DIR), year, day, name)?;
+            Ok((fixture.input, fixture.params))
+        }
+    }
+}
+
+/// The example's parameters followed by the `--param name=value` overrides
+fn merge_params(
+    example_params: Vec<(String, String)>,
+    params: &[(String, String)],
+) -> Vec<(String, String)> {
+    example_params
+        .into_iter()
+        .chain(params.iter().cloned())
+        .collect()
+}
+
+/// Builds the solver of `day` with `params` applied in order
+fn build_solver(
+    day: u32,
+    registration: &Registration,
+    params: &[(String, String)],
+) -> Result<Box<dyn DynSolver>, Box<dyn Error + Send + Sync>> {
+    let mut solver = (registration.solver)();
+    for (name, value) in params {
+        solver
+            .set_param(name, value)
+            .map_err(|e| format!("Day {}: {}", day, e))?;
+    }
+    Ok(solver)
+}
+
+/// What the worker of a day sends back
+enum Message {
+    /// A part is about to be solved, the input is parsed and prepared
+    Started,
+    /// A part is done, whether it has an answer or a failure
+    Part(PartResult),
+    /// The input could not be loaded or parsed
+    Error(Box<dyn Error + Send + Sync>),
+    /// Parsing or preparing the input panicked, which takes every part
+    Panic(String),
+}
+
+/// The next message of a worker, if it comes within `timeout`
+fn receive(
+    receiver: &Receiver<Message>,
+    timeout: Option<Duration>,
+) -> Result<Message, RecvTimeoutError> {
+    match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(RecvTimeoutError::from),
+    }
+}
+
+/// The workers that timed out, with what they were stuck on
+static TIMED_OUT: Mutex<Vec<(String, JoinHandle<()>)>> = Mutex::new(Vec::new());
+
+/// What the timed out workers that are still running were stuck on, e.g.
+/// `Day 16 part 2`
+pub fn leftover_workers() -> Vec<String> {
+    let mut workers = TIMED_OUT.lock().unwrap();
+    workers.retain(|(_, worker)| !worker.is_finished());
+    workers.iter().map(|(stuck, _)| stuck.clone()).collect()
+}
+
+/// Parses and prepares the input and solves `parts` on a worker thread of
+/// their own, waiting up to `timeout` for each of them from when it starts.
+/// Parsing and preparing get a `timeout` of their own before the first part,
+/// and both parts fail with [`Failure::InputTimeout`] when they run past it.
+fn run_day(
+    registration: &'static Registration,
+    (year, day): (i32, u32),
+    part: Option<u8>,
+    raw_input: String,
+    params: Vec<(String, String)>,
+    timeout: Option<Duration>,
+) -> Result<Vec<PartResult>, Box<dyn Error + Send + Sync>> {
+    let (sender, receiver) = mpsc::channel();
+    let worker = thread::Builder::new()
+        .name(format!("day {}", day))
+        .spawn(move || {
+            // Nobody is waiting any more once a part timed out
+            let run = panic::catch_unwind(AssertUnwindSafe(|| {
+                let solver = build_solver(day, registration, &params)?;
+                solver.run_each(
+                    year,
+                    day,
+                    raw_input,
+                    part,
+                    &mut |_| {
+                        let _ = sender.send(Message::Started);
+                    },
+                    &mut |result| {
+                        let _ = sender.send(Message::Part(result));
+                    },
+                )?;
+                Ok::<_, Box<dyn Error + Send + Sync>>(())
+            }));
+            let _ = match run {
+                Ok(Ok(())) => Ok(()),
+                Ok(Err(e)) => sender.send(Message::Error(e)),
+                Err(payload) => sender.send(Message::Panic(panic_message(payload))),
+            };
+        })?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let mut worker = Some(worker);
+    let mut results = Vec::new();
+    let mut failure = None;
+    for part in parts {
+        let mut started = Instant::now();
+        let failed = match &failure {
+            // The parts after a failed one can't run on the same worker either
+            Some(Failure::Timeout(_)) => Failure::Skipped,
+            Some(failure) => failure.clone(),
+            None => {
+                let mut received = receive(&receiver, timeout);
+                let solving = matches!(received, Ok(Message::Started));
+                if solving {
+                    started = Instant::now();
+                    received = receive(&receiver, timeout);
+                }
+                match received {
+                    Ok(Message::Part(result)) => {
+                        results.push(result);
+                        continue;
+                    }
+                    Ok(Message::Error(e)) => return Err(e),
+                    Ok(Message::Panic(message)) => Failure::Panic(message),
+                    Err(RecvTimeoutError::Timeout) => {
+                        let stuck = match solving {
+                            true => format!("Day {} part {}", day, part),
+                            false => format!("Day {} input", day),
+                        };
+                        if let Some(worker) = worker.take() {
+                            TIMED_OUT.lock().unwrap().push((stuck, worker));
+                        }
+                        match solving {
+                            true => Failure::Timeout(timeout.unwrap_or_default()),
+                            false => Failure::InputTimeout(timeout.unwrap_or_default()),
+                        }
+                    }
+                    Ok(Message::Started) | Err(RecvTimeoutError::Disconnected) => {
+                        Failure::Panic(String::from("the worker stopped without a result"))
+                    }
+                }
+            }
+        };
+        results.push(PartResult {
+            year,
+            day,
+            part,
+            answer: None,
+            failure: Some(failed.clone()),
+            parse_time: Duration::ZERO,
+            prepare_time: Duration::ZERO,
+            solve_time: started.elapsed(),
+        });
+        failure.get_or_insert(failed);
+    }
+    Ok(results)
+}
+
+/// Runs the main implementation of the given day, or returns `None` if it has
+/// not been solved yet.
+///
+/// The day runs on a worker thread of its own, which parses and prepares the
+/// input once for both parts. A part that panics gets a [`Failure`] instead of
+/// ending the whole run, and so does one still running `timeout` after it
+/// started, with the parts after it skipped. Timed out workers can't be
+/// stopped, they are left running in the background until the process exits
+/// and listed by [`leftover_workers`].
+pub fn solve(
+    year: i32,
+    day: u32,
+    part: Option<u8>,
+    source: &InputSource,
+    params: &[(String, String)],
+    timeout: Option<Duration>,
+) -> Option<Result<Vec<PartResult>, Box<dyn Error + Send + Sync>>> {
+    let registration = registry::get(year, day)?;
+    Some(solve_with(registration, part, source, params, timeout))
+}
+
+/// Like [`solve`], with one of the implementations of the day from
+/// [`registry::implementations`]
+pub fn solve_with(
+    registration: &'static Registration,
+    part: Option<u8>,
+    source: &InputSource,
+    params: &[(String, String)],
+    timeout: Option<Duration>,
+) -> Result<Vec<PartResult>, Box<dyn Error + Send + Sync>> {
+    let (year, day) = (registration.year, registration.day);
+    let (raw_input, example_params) = load_input(year, day, source)?;
+    let params = merge_params(example_params, params);
+    run_day(registration, (year, day), part, raw_input, params, timeout)
+}
+
+/// What became of a part, with the errors that stop both parts as one of them,
+/// for comparing runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The part ran, with its answer if it has one
+    Answer(Option<Answer>),
+    /// The input could not be read or parsed
+    Error(String),
+    /// The part panicked, ran out of time or was skipped
+    Failure(Failure),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Outcome::Answer(Some(answer)) => write!(f, "{}", answer.summary()),
+            Outcome::Answer(None) => write!(f, "-"),
+            Outcome::Error(e) => write!(f, "ERROR {}", e.lines().next().unwrap_or("")),
+            Outcome::Failure(Failure::Panic(message)) => write!(f, "PANIC {}", message),
+            Outcome::Failure(Failure::Timeout(deadline)) => {
+                write!(f, "TIMEOUT after {:.2?}", deadline)
+            }
+            Outcome::Failure(Failure::InputTimeout(deadline)) => {
+                write!(f, "INPUT TIMEOUT after {:.2?}", deadline)
+            }
+            Outcome::Failure(Failure::Skipped) => write!(f, "SKIPPED"),
+        }
+    }
+}
+
+/// The outcome of both parts of a run, with an error as the outcome of both
+pub fn outcomes(solved: Result<Vec<PartResult>, Box<dyn Error + Send + Sync>>) -> [Outcome; 2] {
+    match solved {
+        Ok(results) => {
+            let mut outcomes = [Outcome::Answer(None), Outcome::Answer(None)];
+            for result in results {
+                outcomes[result.part as usize - 1] = match result.failure {
+                    Some(failure) => Outcome::Failure(failure),
+                    None => Outcome::Answer(result.answer),
+                };
+            }
+            outcomes
+        }
+        Err(e) => [Outcome::Error(e.to_string()), Outcome::Error(e.to_string())],
+    }
+}
+
+/// Your input of `day`, if there is one, followed by every example
+pub fn sources(year: i32, day: u32) -> Vec<InputSource> {
+    let mut sources = Vec::new();
+    if Path::new(&input_path(year, day)).exists() {
+        sources.push(InputSource::Default);
+    }
+    let examples = fixtures::names(Path::new(EXAMPLES_DIR), year, day);
+    sources.extend(examples.into_iter().map(InputSource::Example));
+    sources
+}
+
+/// Benchmarks the given day, or returns `None` if it has not been solved yet
+pub fn bench(
+    year: i32,
+    day: u32,
+    part: Option<u8>,
+    source: &InputSource,
+    params: &[(String, String)],
+    options: &BenchOptions,
+) -> Option<Result<Vec<BenchResult>, Box<dyn Error + Send + Sync>>> {
+    let registration = registry::get(year, day)?;
+    Some(
+        load_input(year, day, source).and_then(|(raw_input, example_params)| {
+            let params = merge_params(example_params, params);
+            let solver = build_solver(day, registration, &params)?;
+            Ok(solver.bench(day, &raw_input, part, options)?)
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse::ParseError, solver::Solver};
+    use std::env;
+
+    /// Panics in the first part and takes far too long in the second
+    struct Stuck;
+
+    impl Solver for Stuck {
+        type Input<'a> = ();
+        type Prepared<'a> = ();
+        type Output1 = u8;
+        type Output2 = u8;
+
+        fn parse_input(&self, _raw_input: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn solve_first(&self, _input: &(), _: &()) -> Option<Self::Output1> {
+            panic!("stuck in the first part")
+        }
+
+        fn solve_second(&self, _input: &(), _: &()) -> Option<Self::Output2> {
+            thread::sleep(Duration::from_secs(10));
+            Some(2)
+        }
+    }
+
+    crate::register!(2015, 24, Stuck);
+
+    /// Takes a while to prepare and `first` milliseconds for the first part,
+    /// and far too long for the second
+    struct Slow {
+        first: u64,
+    }
+
+    impl Solver for Slow {
+        type Input<'a> = ();
+        type Prepared<'a> = ();
+        type Output1 = u8;
+        type Output2 = u8;
+
+        fn parse_input(&self, _raw_input: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn prepare<'a>(&self, _input: &Self::Input<'a>) -> Self::Prepared<'a> {
+            thread::sleep(Duration::from_millis(200));
+        }
+
+        fn solve_first(&self, _input: &(), _: &()) -> Option<Self::Output1> {
+            thread::sleep(Duration::from_millis(self.first));
+            Some(1)
+        }
+
+        fn solve_second(&self, _input: &(), _: &()) -> Option<Self::Output2> {
+            thread::sleep(Duration::from_secs(10));
+            Some(2)
+        }
+
+        fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+            match name {
+                "first" => self.first = crate::solver::parse_param(name, value)?,
+                _ => return Err(format!("unknown parameter {}", name)),
+            }
+            Ok(())
+        }
+    }
+
+    crate::register!(2015, 22, Slow { first: 200 });
+
+    #[test]
+    fn test_members() {
+        let dir = env::temp_dir().join(format!("aoc-members-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for member in ["bob/2022", "alice/2022", "carol/2021", "2022"] {
+            fs::create_dir_all(dir.join(member)).unwrap();
+        }
+        fs::write(dir.join("notes.txt"), "").unwrap();
+
+        assert_eq!(members(&dir, 2022), vec!["alice", "bob"]);
+        assert_eq!(members(&dir, 2021), vec!["carol"]);
+        assert!(members(&dir.join("missing"), 2022).is_empty());
+        assert_eq!(
+            member_input_path("alice", 2022, 12),
+            "inputs/alice/2022/day12.txt"
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_isolation() {
+        let path = env::temp_dir().join(format!("aoc-runner-{}.txt", std::process::id()));
+        fs::write(&path, "1 2 3").unwrap();
+        let source = InputSource::File(path.display().to_string());
+        let timeout = Some(Duration::from_millis(50));
+
+        let results = solve(2015, 24, None, &source, &[], timeout)
+            .unwrap()
+            .unwrap();
+        let failures: Vec<_> = results.iter().map(|r| r.failure.clone()).collect();
+        assert_eq!(
+            failures,
+            vec![
+                Some(Failure::Panic(String::from("stuck in the first part"))),
+                Some(Failure::Timeout(Duration::from_millis(50))),
+            ]
+        );
+        assert!(results.iter().all(|r| r.answer.is_none()));
+
+        // Days that behave are not affected
+        let results = solve(2015, 25, Some(1), &source, &[], timeout)
+            .unwrap()
+            .unwrap();
+        assert_eq!(results[0].answer, Some(crate::solver::Answer::Integer(3)));
+        assert_eq!(results[0].failure, None);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_timeout_per_part() {
+        let path = env::temp_dir().join(format!("aoc-timeout-{}.txt", std::process::id()));
+        fs::write(&path, "").unwrap();
+        let source = InputSource::File(path.display().to_string());
+        let timeout = Duration::from_millis(300);
+
+        // Each part gets the whole timeout, preparing does not count
+        let results = solve(2015, 22, None, &source, &[], Some(timeout));
+        let results = results.unwrap().unwrap();
+        assert_eq!(results[0].answer, Some(crate::solver::Answer::Integer(1)));
+        assert_eq!(results[1].failure, Some(Failure::Timeout(timeout)));
+
+        // The part after a timed out one is skipped
+        let params = [(String::from("first"), String::from("10000"))];
+        let results = solve(2015, 22, None, &source, &params, Some(timeout));
+        let failures: Vec<_> = results
+            .unwrap()
+            .unwrap()
+            .into_iter()
+            .map(|r| r.failure)
+            .collect();
+        assert_eq!(
+            failures,
+            vec![Some(Failure::Timeout(timeout)), Some(Failure::Skipped)]
+        );
+        assert!(leftover_workers().contains(&String::from("Day 22 part 1")));
+
+        // Preparing past the timeout takes both parts
+        let timeout = Duration::from_millis(100);
+        let results = solve(2015, 22, None, &source, &[], Some(timeout));
+        let failures: Vec<_> = results
+            .unwrap()
+            .unwrap()
+            .into_iter()
+            .map(|r| r.failure)
+            .collect();
+        assert_eq!(failures, vec![Some(Failure::InputTimeout(timeout)); 2]);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_outcome() {
+        let timeout = Outcome::Failure(Failure::Timeout(Duration::from_secs(30)));
+        assert_eq!(timeout.to_string(), "TIMEOUT after 30.00s");
+        assert_eq!(Outcome::Failure(Failure::Skipped).to_string(), "SKIPPED");
+        let error = Outcome::Error(String::from("Day 9: expected a direction\nR x"));
+        assert_eq!(error.to_string(), "ERROR Day 9: expected a direction");
+    }
+}
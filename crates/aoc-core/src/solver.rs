@@ -0,0 +1,282 @@
+//! The trait every day implements
+
+use std::{
+    any::Any,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{normalize::Normalization, parse::ParseError};
+
+/// An answer in the shape the puzzle asks for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// A number, the shape of most answers
+    Integer(i128),
+    /// A word or a sequence, like crate labels
+    Text(String),
+    /// A picture, like a screen
+    Grid {
+        /// The picture, one line per row
+        rows: String,
+        /// Why the picture is the answer instead of what it shows, if the day
+        /// tried to read it
+        note: Option<String>,
+    },
+}
+
+impl Answer {
+    /// A one-line version for tables, grids are too tall to show inline
+    pub fn summary(&self) -> String {
+        match self {
+            Answer::Grid { rows, .. } => {
+                let w = rows.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+                format!("<{}x{} grid>", w, rows.lines().count())
+            }
+            answer => answer.to_string(),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid { rows, .. } => write!(f, "{}", rows),
+        }
+    }
+}
+
+macro_rules! integer_answers {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Integer(n.into())
+            }
+        })*
+    };
+}
+
+integer_answers!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Integer(n as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Answer::Integer(n as i128)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        // Too large to be an `Integer`, but it still reads the same as text
+        i128::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Integer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// Why a part that was run in isolation gave no result at all
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The part panicked, with the panic message
+    Panic(String),
+    /// The part was still running when its deadline passed
+    Timeout(Duration),
+    /// Parsing or preparing the input was still running when its deadline
+    /// passed, which takes every part
+    InputTimeout(Duration),
+    /// The part was not run, an earlier part on the same worker timed out
+    Skipped,
+}
+
+impl Failure {
+    /// A short status for tables
+    pub fn summary(&self) -> &'static str {
+        match self {
+            Failure::Panic(_) => "PANIC",
+            Failure::Timeout(_) => "TIMEOUT",
+            Failure::InputTimeout(_) => "INPUT TIMEOUT",
+            Failure::Skipped => "SKIPPED",
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Failure::Panic(message) => write!(f, "panicked: {}", message),
+            Failure::Timeout(deadline) => write!(f, "timed out after {:.2?}", deadline),
+            Failure::InputTimeout(deadline) => write!(
+                f,
+                "not run, parsing and preparing the input timed out after {:.2?}",
+                deadline
+            ),
+            Failure::Skipped => write!(f, "not run after an earlier part timed out"),
+        }
+    }
+}
+
+/// The message a panic was started with, if it has one
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => String::from("unknown panic"),
+        },
+    }
+}
+
+/// The answer of one part of a day, with how long it took
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartResult {
+    /// The year of the day
+    pub year: i32,
+    /// The day of the puzzle
+    pub day: u32,
+    /// 1 or 2
+    pub part: u8,
+    /// The answer, `None` if the part is not solved or failed
+    pub answer: Option<Answer>,
+    /// Set when the part panicked or ran out of time, with no answer then
+    pub failure: Option<Failure>,
+    /// Time spent in [`Solver::parse_input`], shared by both parts
+    pub parse_time: Duration,
+    /// Time spent in [`Solver::prepare`], shared by both parts
+    pub prepare_time: Duration,
+    /// Time spent solving this part
+    pub solve_time: Duration,
+}
+
+/// A day of the puzzle: its input is parsed and prepared once and shared by
+/// both parts, which can answer with different types
+///
+/// The parsed input can borrow from the raw input, e.g. `type Input<'a> =
+/// Vec<&'a str>`, which is kept alive until both parts are solved.
+pub trait Solver {
+    /// The parsed input
+    type Input<'a>;
+    /// Work both parts need, done once by [`Solver::prepare`], `()` for days
+    /// without any
+    type Prepared<'a>: Default;
+    /// The answer to the first part
+    type Output1: Into<Answer>;
+    /// The answer to the second part
+    type Output2: Into<Answer>;
+
+    /// Parses the raw input, after [`Solver::normalization`]
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError>;
+    /// The expensive preprocessing shared by both parts, timed on its own
+    fn prepare<'a>(&self, _input: &Self::Input<'a>) -> Self::Prepared<'a> {
+        Self::Prepared::default()
+    }
+    /// The answer to the first part, `None` if it has not been solved
+    fn solve_first(
+        &self,
+        input: &Self::Input<'_>,
+        prepared: &Self::Prepared<'_>,
+    ) -> Option<Self::Output1>;
+    /// The answer to the second part, `None` if it has not been solved
+    fn solve_second(
+        &self,
+        input: &Self::Input<'_>,
+        prepared: &Self::Prepared<'_>,
+    ) -> Option<Self::Output2>;
+
+    /// Overrides a puzzle parameter, e.g. `--param row=10`. Days with
+    /// parameters keep them as fields with the real puzzle values as defaults.
+    fn set_param(&mut self, name: &str, _value: &str) -> Result<(), String> {
+        Err(format!("unknown parameter {}", name))
+    }
+
+    /// How the raw input is cleaned up before [`Solver::parse_input`] sees it,
+    /// everything by default. Days that care about trailing whitespace turn
+    /// steps off.
+    fn normalization(&self) -> Normalization {
+        Normalization::ALL
+    }
+
+    /// [`Solver::prepare`] and how long it took
+    fn timed_prepare<'a>(&self, input: &Self::Input<'a>) -> (Self::Prepared<'a>, Duration) {
+        let before = Instant::now();
+        let prepared = self.prepare(input);
+
+        (prepared, before.elapsed())
+    }
+
+    /// [`Solver::solve_first`] and how long it took
+    fn timed_first(
+        &self,
+        input: &Self::Input<'_>,
+        prepared: &Self::Prepared<'_>,
+    ) -> (Option<Self::Output1>, Duration) {
+        let before = Instant::now();
+        let solution = self.solve_first(input, prepared);
+
+        (solution, before.elapsed())
+    }
+
+    /// [`Solver::solve_second`] and how long it took
+    fn timed_second(
+        &self,
+        input: &Self::Input<'_>,
+        prepared: &Self::Prepared<'_>,
+    ) -> (Option<Self::Output2>, Duration) {
+        let before = Instant::now();
+        let solution = self.solve_second(input, prepared);
+
+        (solution, before.elapsed())
+    }
+}
+
+/// Parses the value of a parameter for [`Solver::set_param`]
+pub fn parse_param<T: FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value {} for parameter {}", value, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::from(24000_usize), Answer::Integer(24000));
+        assert_eq!(Answer::from(-3_isize).to_string(), "-3");
+        assert_eq!(Answer::from("CMZ"), Answer::Text(String::from("CMZ")));
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+
+        let grid = Answer::Grid {
+            rows: String::from("#..\n.#."),
+            note: Some(String::from("not letters")),
+        };
+        assert_eq!(grid.to_string(), "#..\n.#.");
+        assert_eq!(grid.summary(), "<3x2 grid>");
+    }
+
+    #[test]
+    fn test_panic_message() {
+        let payload = std::panic::catch_unwind(|| panic!("valve {} is missing", "AA"));
+        assert_eq!(panic_message(payload.unwrap_err()), "valve AA is missing");
+        let payload = std::panic::catch_unwind(|| panic!("no path"));
+        assert_eq!(panic_message(payload.unwrap_err()), "no path");
+    }
+}
@@ -0,0 +1,167 @@
+//! 2D grids and points for Advent of Code puzzles.
+//!
+//! Positions are anything implementing [`Coord`], with `x` growing to the
+//! right and `y` growing downwards like the lines of a puzzle input.
+//!
+//! ```
+//! use aoc_grid::{Coord, Grid, GridPoint};
+//!
+//! let grid = Grid::new("#..##.".chars().collect(), 2, 3);
+//! assert_eq!(grid.get(&GridPoint::new(1, 1)), Some(&'#'));
+//! assert_eq!(grid.filter('#').len(), 3);
+//! assert_eq!(GridPoint::new(0, 0).distance(&GridPoint::new(2, 1)), 3);
+//! ```
+
+#![warn(missing_docs)]
+
+use core::fmt;
+use std::fmt::{Debug, Display, Formatter};
+
+/// A position on a grid
+pub trait Coord {
+    /// The column, growing to the right
+    fn x(&self) -> isize;
+    /// The row, growing downwards
+    fn y(&self) -> isize;
+
+    /// Manhattan distance to `other`
+    fn distance(&self, other: &Self) -> usize {
+        self.x().abs_diff(other.x()) + self.y().abs_diff(other.y())
+    }
+}
+
+/// A point that may fall outside a grid, e.g. while looking at neighbours
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GridPoint {
+    x: isize,
+    y: isize,
+}
+
+impl GridPoint {
+    /// The point in column `x` of row `y`
+    pub fn new(x: isize, y: isize) -> Self {
+        GridPoint { x, y }
+    }
+
+    /// The point moved by an offset, like one of [`ORTHOGONAL_DIRS`]
+    pub fn add(&self, (x, y): (isize, isize)) -> Self {
+        Self {
+            x: self.x + x,
+            y: self.y + y,
+        }
+    }
+}
+
+impl Coord for GridPoint {
+    fn x(&self) -> isize {
+        self.x
+    }
+
+    fn y(&self) -> isize {
+        self.y
+    }
+}
+
+impl Coord for (usize, usize) {
+    fn x(&self) -> isize {
+        self.0 as isize
+    }
+
+    fn y(&self) -> isize {
+        self.1 as isize
+    }
+}
+
+/// Offsets to the four neighbours sharing a side with a point
+pub const ORTHOGONAL_DIRS: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// A rectangular grid stored row by row
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    /// The number of cells in a row
+    pub w: usize,
+    /// The number of rows
+    pub h: usize,
+}
+
+impl<T: Clone + Copy + PartialEq + Debug> Grid<T> {
+    /// A grid of `h` rows of `w` cells each, `cells` goes row by row
+    pub fn new(cells: Vec<T>, h: usize, w: usize) -> Self {
+        Self { cells, w, h }
+    }
+
+    fn index(&self, c: &impl Coord) -> Option<usize> {
+        let (x, y) = (c.x(), c.y());
+        let inside = (0..self.w as isize).contains(&x) && (0..self.h as isize).contains(&y);
+        inside.then_some((x + y * self.w as isize) as usize)
+    }
+
+    /// The cell at `c`, `None` outside the grid
+    pub fn get(&self, c: &impl Coord) -> Option<&T> {
+        self.cells.get(self.index(c)?)
+    }
+
+    /// Replaces the cell at `c`, which must be inside the grid
+    pub fn set(&mut self, c: &impl Coord, v: T) {
+        let Some(i) = self.index(c) else {
+            panic!(
+                "({}, {}) is outside the {}x{} grid",
+                c.x(),
+                c.y(),
+                self.w,
+                self.h
+            );
+        };
+        self.cells[i] = v;
+    }
+
+    /// Every point holding `needle`
+    pub fn filter(&self, needle: T) -> Vec<GridPoint> {
+        self.cells
+            .iter()
+            .cloned()
+            .enumerate()
+            .filter_map(|(i, v)| {
+                (needle == v).then_some(GridPoint::new(
+                    (i % self.w) as isize,
+                    (i as f64 / self.w as f64).floor() as isize,
+                ))
+            })
+            .collect()
+    }
+}
+
+impl<T: fmt::Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        for row in self.cells.chunks(self.w) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = Grid::new(vec![0; 6], 2, 3);
+        grid.set(&GridPoint::new(2, 1), 5);
+        assert_eq!(grid.get(&GridPoint::new(2, 1)), Some(&5));
+        assert_eq!(grid.get(&GridPoint::new(-1, 1)), None);
+        assert_eq!(grid.get(&GridPoint::new(3, 0)), None);
+        assert_eq!(grid.get(&GridPoint::new(0, 2)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "(3, 0) is outside the 3x2 grid")]
+    fn test_set_outside() {
+        let mut grid = Grid::new(vec![0; 6], 2, 3);
+        grid.set(&GridPoint::new(3, 0), 7);
+    }
+}
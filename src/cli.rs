@@ -0,0 +1,739 @@
+use std::{iter::Peekable, ops::RangeInclusive, str::FromStr, time::Duration};
+
+use aoc_core::{bench::BenchOptions, fixtures, report::Format, runner::InputSource};
+
+use crate::scaffold::Template;
+
+/// The year of commands that don't name one
+pub const DEFAULT_YEAR: i32 = 2022;
+
+pub const USAGE: &str = "Usage:
+    aoc [YEAR] <all|DAY|FROM..=TO|FROM..TO> [--part 1|2]
+        [--input PATH | --stdin | --example [NAME]]
+        [--format text|json|csv] [--jobs N] [--param NAME=VALUE]... [--timeout SECS]
+    aoc list
+    aoc fetch <DAY> [--year YEAR]
+    aoc submit <DAY> <PART> [--year YEAR]
+    aoc new <DAY> [--year YEAR] [--template lines|grid|graph]
+    aoc verify [--record] [--timeout SECS]
+    aoc watch <DAY> [--year YEAR]
+    aoc matrix [YEAR] [--jobs N] [--timeout SECS]
+    aoc crosscheck <DAY> [--year YEAR] [--timeout SECS]
+    aoc gen <DAY> [--year YEAR] [--seed N] [--size N]
+    aoc bench [YEAR] <DAYS> [--part 1|2] [--input PATH | --stdin | --example [NAME]]
+              [--param NAME=VALUE]... [--iterations N] [--warmup N]";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Days {
+    All,
+    Range(RangeInclusive<u32>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunArgs {
+    pub year: i32,
+    pub days: Days,
+    pub part: Option<u8>,
+    pub source: InputSource,
+    pub format: Format,
+    /// Days run in parallel, all available cores if not given
+    pub jobs: Option<usize>,
+    /// Puzzle parameter overrides from `--param NAME=VALUE`, in order
+    pub params: Vec<(String, String)>,
+    /// How long each part may run before it is reported as timed out
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Run(RunArgs),
+    List,
+    Fetch {
+        day: u32,
+        year: i32,
+    },
+    Submit {
+        day: u32,
+        part: u8,
+        year: i32,
+    },
+    New {
+        day: u32,
+        year: i32,
+        template: Template,
+    },
+    Verify {
+        record: bool,
+        timeout: Option<Duration>,
+    },
+    Bench(RunArgs, BenchOptions),
+    Watch {
+        day: u32,
+        year: i32,
+    },
+    /// Every solved day on every team member's inputs
+    Matrix {
+        year: i32,
+        jobs: Option<usize>,
+        timeout: Option<Duration>,
+    },
+    /// Every implementation of a day on its input and examples
+    Crosscheck {
+        day: u32,
+        year: i32,
+        timeout: Option<Duration>,
+    },
+    /// A random input of a day, written to stdout
+    Gen {
+        day: u32,
+        year: i32,
+        seed: u64,
+        /// The size of a real input if not given
+        size: Option<usize>,
+    },
+}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter().peekable();
+    match args.peek().map(String::as_str) {
+        Some("list") => {
+            args.next();
+            no_more_args(args)?;
+            Ok(Command::List)
+        }
+        Some("fetch") => {
+            args.next();
+            let day = parse_day(&args.next().ok_or("Day is required")?)?;
+            let year = parse_year_flags(args)?;
+            Ok(Command::Fetch { day, year })
+        }
+        Some("submit") => {
+            args.next();
+            let day = parse_day(&args.next().ok_or("Day is required")?)?;
+            let part = parse_part(&args.next().ok_or("Part is required")?)?;
+            let year = parse_year_flags(args)?;
+            Ok(Command::Submit { day, part, year })
+        }
+        Some("new") => {
+            args.next();
+            let day = parse_day(&args.next().ok_or("Day is required")?)?;
+            let mut year = DEFAULT_YEAR;
+            let mut template = Template::Lines;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--year" => year = parse_year(&args.next().ok_or("--year requires a value")?)?,
+                    "--template" => {
+                        let name = args.next().ok_or("--template requires a name")?;
+                        template = Template::parse(&name).ok_or(format!(
+                            "Unknown template {}, expected lines, grid or graph",
+                            name
+                        ))?
+                    }
+                    extra => return Err(format!("Unexpected argument {}", extra)),
+                }
+            }
+            Ok(Command::New {
+                day,
+                year,
+                template,
+            })
+        }
+        Some("verify") => {
+            args.next();
+            let mut record = false;
+            let mut timeout = None;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--record" => record = true,
+                    "--timeout" => timeout = Some(parse_timeout(args.next())?),
+                    extra => return Err(format!("Unexpected argument {}", extra)),
+                }
+            }
+            Ok(Command::Verify { record, timeout })
+        }
+        Some("watch") => {
+            args.next();
+            let day = parse_day(&args.next().ok_or("Day is required")?)?;
+            let year = parse_year_flags(args)?;
+            Ok(Command::Watch { day, year })
+        }
+        Some("matrix") => {
+            args.next();
+            let year = match args.next_if(|arg| !arg.starts_with("--")) {
+                Some(year) => parse_year(&year)?,
+                None => DEFAULT_YEAR,
+            };
+            let mut jobs = None;
+            let mut timeout = None;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--jobs" => jobs = Some(parse_jobs(args.next())?),
+                    "--timeout" => timeout = Some(parse_timeout(args.next())?),
+                    extra => return Err(format!("Unexpected argument {}", extra)),
+                }
+            }
+            Ok(Command::Matrix {
+                year,
+                jobs,
+                timeout,
+            })
+        }
+        Some("crosscheck") => {
+            args.next();
+            let day = parse_day(&args.next().ok_or("Day is required")?)?;
+            let mut year = DEFAULT_YEAR;
+            let mut timeout = None;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--year" => year = parse_year(&args.next().ok_or("--year requires a value")?)?,
+                    "--timeout" => timeout = Some(parse_timeout(args.next())?),
+                    extra => return Err(format!("Unexpected argument {}", extra)),
+                }
+            }
+            Ok(Command::Crosscheck { day, year, timeout })
+        }
+        Some("gen") => {
+            args.next();
+            let day = parse_day(&args.next().ok_or("Day is required")?)?;
+            let mut year = DEFAULT_YEAR;
+            let mut seed = 0;
+            let mut size = None;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--year" => year = parse_year(&args.next().ok_or("--year requires a value")?)?,
+                    "--seed" => seed = parse_number(&arg, args.next())?,
+                    "--size" => size = Some(parse_number(&arg, args.next())?),
+                    extra => return Err(format!("Unexpected argument {}", extra)),
+                }
+            }
+            Ok(Command::Gen {
+                day,
+                year,
+                seed,
+                size,
+            })
+        }
+        Some("bench") => {
+            args.next();
+            let (args, options) = parse_bench(args)?;
+            Ok(Command::Bench(args, options))
+        }
+        _ => Ok(Command::Run(parse_run(args)?)),
+    }
+}
+
+fn no_more_args(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    match args.next() {
+        Some(extra) => Err(format!("Unexpected argument {}", extra)),
+        None => Ok(()),
+    }
+}
+
+fn parse_run<I: Iterator<Item = String>>(mut args: Peekable<I>) -> Result<RunArgs, String> {
+    let mut year = None;
+    let mut days = None;
+    let mut part = None;
+    let mut source = InputSource::Default;
+    let mut format = Format::Text;
+    let mut jobs = None;
+    let mut params = Vec::new();
+    let mut timeout = None;
+    let mut set_source = |new_source| match source {
+        InputSource::Default => {
+            source = new_source;
+            Ok(())
+        }
+        _ => Err("Only one of --input, --stdin and --example can be used"),
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let value = args.next().ok_or("--part requires a value")?;
+                part = Some(parse_part(&value)?);
+            }
+            "--input" => {
+                let path = args.next().ok_or("--input requires a path")?;
+                set_source(InputSource::File(path))?;
+            }
+            "--stdin" => set_source(InputSource::Stdin)?,
+            "--jobs" => jobs = Some(parse_jobs(args.next())?),
+            "--param" => {
+                let param = args.next().ok_or("--param requires NAME=VALUE")?;
+                match param.split_once('=') {
+                    Some((name, value)) if !name.is_empty() => {
+                        params.push((name.to_string(), value.to_string()))
+                    }
+                    _ => return Err(format!("Invalid parameter {}, expected NAME=VALUE", param)),
+                }
+            }
+            "--timeout" => timeout = Some(parse_timeout(args.next())?),
+            "--format" => {
+                format = match args.next().as_deref() {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some("csv") => Format::Csv,
+                    _ => return Err(String::from("--format must be text, json or csv")),
+                }
+            }
+            "--example" => {
+                // A name unless it is the year or day selection, like in
+                // `aoc --example 9`
+                let name = args
+                    .next_if(|n| {
+                        !n.starts_with("--")
+                            && (days.is_some()
+                                || (parse_days(n).is_err() && parse_year(n).is_err()))
+                    })
+                    .unwrap_or_else(|| String::from(fixtures::DEFAULT_NAME));
+                set_source(InputSource::Example(name))?;
+            }
+            flag if flag.starts_with("--") => return Err(format!("Unknown flag {}", flag)),
+            // Years can't be mistaken for days, they start at 2015
+            selection if year.is_none() && days.is_none() && parse_year(selection).is_ok() => {
+                year = Some(parse_year(selection)?)
+            }
+            selection if days.is_none() => days = Some(parse_days(selection)?),
+            extra => return Err(format!("Unexpected argument {}", extra)),
+        }
+    }
+
+    let days = days.ok_or("Day is required")?;
+    let single_day = matches!(&days, Days::Range(range) if range.start() == range.end());
+    if matches!(source, InputSource::File(_) | InputSource::Stdin) && !single_day {
+        return Err(String::from("--input and --stdin need a single day"));
+    }
+
+    Ok(RunArgs {
+        year: year.unwrap_or(DEFAULT_YEAR),
+        days,
+        part,
+        source,
+        format,
+        jobs,
+        params,
+        timeout,
+    })
+}
+
+fn parse_bench(mut args: impl Iterator<Item = String>) -> Result<(RunArgs, BenchOptions), String> {
+    let mut options = BenchOptions::default();
+    let mut run_args = Vec::new();
+
+    while let Some(arg) = args.next() {
+        let count = match arg.as_str() {
+            "--iterations" => &mut options.iterations,
+            "--warmup" => &mut options.warmup,
+            _ => {
+                run_args.push(arg);
+                continue;
+            }
+        };
+        *count = parse_number(&arg, args.next())?;
+    }
+    if options.iterations == 0 {
+        return Err(String::from("--iterations must be at least 1"));
+    }
+
+    let run_args = parse_run(run_args.into_iter().peekable())?;
+    if run_args.format != Format::Text {
+        return Err(String::from("bench only supports the text format"));
+    }
+    if run_args.jobs.is_some() {
+        return Err(String::from("bench always runs one day at a time"));
+    }
+    if run_args.timeout.is_some() {
+        return Err(String::from("bench does not support --timeout"));
+    }
+    Ok((run_args, options))
+}
+
+fn parse_number<N: FromStr>(flag: &str, value: Option<String>) -> Result<N, String> {
+    value
+        .and_then(|n| n.parse().ok())
+        .ok_or(format!("{} requires a number", flag))
+}
+
+fn parse_jobs(value: Option<String>) -> Result<usize, String> {
+    match value.and_then(|n| n.parse().ok()) {
+        Some(n @ 1..) => Ok(n),
+        _ => Err(String::from("--jobs requires a positive number")),
+    }
+}
+
+fn parse_timeout(value: Option<String>) -> Result<Duration, String> {
+    match value.and_then(|s| s.parse::<f64>().ok()) {
+        Some(secs) if secs > 0.0 && secs.is_finite() => Ok(Duration::from_secs_f64(secs)),
+        _ => Err(String::from(
+            "--timeout requires a positive number of seconds",
+        )),
+    }
+}
+
+fn parse_part(s: &str) -> Result<u8, String> {
+    match s.parse() {
+        Ok(p @ 1..=2) => Ok(p),
+        _ => Err(format!("Invalid part {}, expected 1 or 2", s)),
+    }
+}
+
+fn parse_day(s: &str) -> Result<u32, String> {
+    match s.parse() {
+        Ok(d @ 1..=25) => Ok(d),
+        _ => Err(format!("Invalid day {}, expected 1 to 25", s)),
+    }
+}
+
+fn parse_year(s: &str) -> Result<i32, String> {
+    match s.parse() {
+        Ok(y @ 2015..) => Ok(y),
+        _ => Err(format!(
+            "Invalid year {}, Advent of Code started in 2015",
+            s
+        )),
+    }
+}
+
+/// The year of a command whose only flag is `--year`
+fn parse_year_flags(mut args: impl Iterator<Item = String>) -> Result<i32, String> {
+    let mut year = DEFAULT_YEAR;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(&args.next().ok_or("--year requires a value")?)?,
+            extra => return Err(format!("Unexpected argument {}", extra)),
+        }
+    }
+    Ok(year)
+}
+
+fn parse_days(s: &str) -> Result<Days, String> {
+    if s == "all" {
+        return Ok(Days::All);
+    }
+
+    let range = if let Some((from, to)) = s.split_once("..=") {
+        parse_day(from)?..=parse_day(to)?
+    } else if let Some((from, to)) = s.split_once("..") {
+        parse_day(from)?..=parse_day(to)? - 1
+    } else {
+        let day = parse_day(s)?;
+        day..=day
+    };
+
+    if range.is_empty() {
+        return Err(format!("Empty day range {}", s));
+    }
+    Ok(Days::Range(range))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Result<RunArgs, String> {
+        match parse(s.split_whitespace().map(String::from))? {
+            Command::Run(args) => Ok(args),
+            command => panic!("Unexpected command {:?}", command),
+        }
+    }
+
+    #[test]
+    fn test_single_day() {
+        let result = args("12 --part 2");
+        assert_eq!(
+            result,
+            Ok(RunArgs {
+                year: 2022,
+                days: Days::Range(12..=12),
+                part: Some(2),
+                source: InputSource::Default,
+                format: Format::Text,
+                jobs: None,
+                params: vec![],
+                timeout: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_ranges() {
+        assert_eq!(args("3..=9").unwrap().days, Days::Range(3..=9));
+        assert_eq!(args("3..9").unwrap().days, Days::Range(3..=8));
+        assert_eq!(args("all").unwrap().days, Days::All);
+    }
+
+    #[test]
+    fn test_years() {
+        let year_days = |s| args(s).map(|a| (a.year, a.days));
+        assert_eq!(year_days("2023 5"), Ok((2023, Days::Range(5..=5))));
+        assert_eq!(year_days("2015 all"), Ok((2015, Days::All)));
+        assert_eq!(year_days("5"), Ok((2022, Days::Range(5..=5))));
+        assert_eq!(
+            args("2023 --example 5").map(|a| (a.year, a.days, a.source)),
+            Ok((
+                2023,
+                Days::Range(5..=5),
+                InputSource::Example(String::from("example"))
+            ))
+        );
+        assert!(args("2023").is_err());
+        assert!(args("2023 2024 5").is_err());
+        assert!(args("5 2023").is_err());
+    }
+
+    #[test]
+    fn test_sources() {
+        let source = |s| args(s).map(|a| a.source);
+        assert_eq!(
+            source("3 --input foo.txt"),
+            Ok(InputSource::File(String::from("foo.txt")))
+        );
+        assert_eq!(source("3 --stdin"), Ok(InputSource::Stdin));
+        let example = |name: &str| Ok(InputSource::Example(String::from(name)));
+        assert_eq!(source("all --example"), example("example"));
+        assert_eq!(source("9 --example larger"), example("larger"));
+        assert_eq!(source("--example 9"), example("example"));
+        assert_eq!(args("--example 9").unwrap().days, Days::Range(9..=9));
+        assert_eq!(source("--example larger 9"), example("larger"));
+        assert!(source("1..=3 --stdin").is_err());
+        assert!(source("3 --stdin --example").is_err());
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(args("all --format json").unwrap().format, Format::Json);
+        assert_eq!(args("all --format csv").unwrap().format, Format::Csv);
+        assert!(args("all --format xml").is_err());
+    }
+
+    #[test]
+    fn test_jobs() {
+        assert_eq!(args("all --jobs 4").unwrap().jobs, Some(4));
+        assert!(args("all --jobs 0").is_err());
+    }
+
+    #[test]
+    fn test_timeout() {
+        let timeout = |s| args(s).map(|a| a.timeout);
+        assert_eq!(
+            timeout("all --timeout 2.5"),
+            Ok(Some(Duration::from_millis(2500)))
+        );
+        assert_eq!(timeout("all"), Ok(None));
+        assert!(timeout("all --timeout 0").is_err());
+        assert!(timeout("all --timeout soon").is_err());
+    }
+
+    #[test]
+    fn test_params() {
+        let params = |s| args(s).map(|a| a.params);
+        assert_eq!(
+            params("15 --param row=10 --param at_most=20"),
+            Ok(vec![
+                (String::from("row"), String::from("10")),
+                (String::from("at_most"), String::from("20")),
+            ])
+        );
+        assert!(params("15 --param row").is_err());
+        assert!(params("15 --param =10").is_err());
+    }
+
+    #[test]
+    fn test_list() {
+        let command = parse(vec![String::from("list")]);
+        assert_eq!(command, Ok(Command::List));
+    }
+
+    #[test]
+    fn test_fetch() {
+        let command = |s: &str| parse(s.split_whitespace().map(String::from));
+        assert_eq!(
+            command("fetch 3"),
+            Ok(Command::Fetch { day: 3, year: 2022 })
+        );
+        assert_eq!(
+            command("fetch 3 --year 2021"),
+            Ok(Command::Fetch { day: 3, year: 2021 })
+        );
+        assert!(command("fetch 3 --year 2014").is_err());
+        assert!(command("fetch").is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let command = |s: &str| parse(s.split_whitespace().map(String::from));
+        assert_eq!(
+            command("submit 3 2 --year 2021"),
+            Ok(Command::Submit {
+                day: 3,
+                part: 2,
+                year: 2021
+            })
+        );
+        assert!(command("submit 3").is_err());
+        assert!(command("submit 3 3").is_err());
+    }
+
+    #[test]
+    fn test_new() {
+        let command = |s: &str| parse(s.split_whitespace().map(String::from));
+        assert_eq!(
+            command("new 17"),
+            Ok(Command::New {
+                day: 17,
+                year: 2022,
+                template: Template::Lines
+            })
+        );
+        assert_eq!(
+            command("new 17 --year 2023 --template grid"),
+            Ok(Command::New {
+                day: 17,
+                year: 2023,
+                template: Template::Grid
+            })
+        );
+        assert_eq!(
+            command("new 17 --template grid --year 2023"),
+            command("new 17 --year 2023 --template grid")
+        );
+        assert!(command("new 17 --year").is_err());
+        assert!(command("new 17 --part 1").is_err());
+        assert!(command("new 17 --template tree").is_err());
+        assert!(command("new").is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let command = |s: &str| parse(s.split_whitespace().map(String::from));
+        assert_eq!(
+            command("verify"),
+            Ok(Command::Verify {
+                record: false,
+                timeout: None
+            })
+        );
+        assert_eq!(
+            command("verify --timeout 10 --record"),
+            Ok(Command::Verify {
+                record: true,
+                timeout: Some(Duration::from_secs(10))
+            })
+        );
+        assert!(command("verify 3").is_err());
+    }
+
+    #[test]
+    fn test_watch() {
+        let command = |s: &str| parse(s.split_whitespace().map(String::from));
+        assert_eq!(
+            command("watch 9 --year 2021"),
+            Ok(Command::Watch { day: 9, year: 2021 })
+        );
+        assert!(command("watch").is_err());
+        assert!(command("watch 9 --part 1").is_err());
+    }
+
+    #[test]
+    fn test_matrix() {
+        let command = |s: &str| parse(s.split_whitespace().map(String::from));
+        assert_eq!(
+            command("matrix"),
+            Ok(Command::Matrix {
+                year: 2022,
+                jobs: None,
+                timeout: None
+            })
+        );
+        assert_eq!(
+            command("matrix 2021 --timeout 5 --jobs 2"),
+            Ok(Command::Matrix {
+                year: 2021,
+                jobs: Some(2),
+                timeout: Some(Duration::from_secs(5))
+            })
+        );
+        assert!(command("matrix 12").is_err());
+        assert!(command("matrix --part 1").is_err());
+    }
+
+    #[test]
+    fn test_crosscheck() {
+        let command = |s: &str| parse(s.split_whitespace().map(String::from));
+        assert_eq!(
+            command("crosscheck 15"),
+            Ok(Command::Crosscheck {
+                day: 15,
+                year: 2022,
+                timeout: None
+            })
+        );
+        assert_eq!(
+            command("crosscheck 15 --timeout 5 --year 2021"),
+            Ok(Command::Crosscheck {
+                day: 15,
+                year: 2021,
+                timeout: Some(Duration::from_secs(5))
+            })
+        );
+        assert!(command("crosscheck").is_err());
+        assert!(command("crosscheck 15 --part 1").is_err());
+    }
+
+    #[test]
+    fn test_gen() {
+        let command = |s: &str| parse(s.split_whitespace().map(String::from));
+        assert_eq!(
+            command("gen 16"),
+            Ok(Command::Gen {
+                day: 16,
+                year: 2022,
+                seed: 0,
+                size: None
+            })
+        );
+        assert_eq!(
+            command("gen 13 --size 1000 --seed 42"),
+            Ok(Command::Gen {
+                day: 13,
+                year: 2022,
+                seed: 42,
+                size: Some(1000)
+            })
+        );
+        assert!(command("gen").is_err());
+        assert!(command("gen 13 --seed -1").is_err());
+        assert!(command("gen 13 --size").is_err());
+    }
+
+    #[test]
+    fn test_bench() {
+        let command = parse(
+            "bench 15..=16 --iterations 5 --part 1"
+                .split(' ')
+                .map(String::from),
+        );
+        let Ok(Command::Bench(args, options)) = command else {
+            panic!("Unexpected command {:?}", command);
+        };
+        assert_eq!(args.days, Days::Range(15..=16));
+        assert_eq!(args.part, Some(1));
+        assert_eq!(
+            options,
+            BenchOptions {
+                warmup: 2,
+                iterations: 5
+            }
+        );
+        assert!(parse("bench 1 --warmup".split(' ').map(String::from)).is_err());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(args("").is_err());
+        assert!(args("26").is_err());
+        assert!(args("9..=3").is_err());
+        assert!(args("1 --part 3").is_err());
+        assert!(args("1 2").is_err());
+    }
+}
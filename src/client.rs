@@ -0,0 +1,271 @@
+use std::{
+    env,
+    error::Error,
+    fs,
+    io::ErrorKind,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::Deserialize;
+
+pub const CONFIG_PATH: &str = "aoc.toml";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/jenarvaezg/aoc2022";
+
+/// Settings from `aoc.toml`, `AOC_SESSION` and `AOC_BASE_URL` take precedence
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub fn load(path: &str) -> Result<Self, Box<dyn Error>> {
+        let mut config: Config = match fs::read_to_string(path) {
+            Ok(raw) => toml::from_str(&raw).map_err(|e| format!("{}: {}", path, e))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => Config::default(),
+            Err(e) => return Err(format!("{}: {}", path, e).into()),
+        };
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+        Ok(config)
+    }
+}
+
+/// When a puzzle unlocks, midnight EST (05:00 UTC) on December `day`
+pub fn unlock_time(year: i32, day: u32) -> SystemTime {
+    // Days since the epoch of `year`-12-`day`, from Howard Hinnant's
+    // days_from_civil with March as the first month of the year
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400) as i64;
+    let day_of_year = (153 * (12 - 3) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era as i64 * 146097 + day_of_era - 719468;
+
+    UNIX_EPOCH + Duration::from_secs((days * 86400 + 5 * 3600) as u64)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetch {
+    /// The input was already in the cache and was not downloaded again
+    Cached,
+    Downloaded,
+}
+
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        let base_url = config
+            .base_url
+            .unwrap_or_else(|| String::from(DEFAULT_BASE_URL));
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: config.session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    fn session(&self) -> Result<&str, Box<dyn Error>> {
+        self.session.as_deref().ok_or_else(|| {
+            format!(
+                "No session cookie, set AOC_SESSION or `session` in {}",
+                CONFIG_PATH
+            )
+            .into()
+        })
+    }
+
+    fn check_unlocked(year: i32, day: u32, now: SystemTime) -> Result<(), Box<dyn Error>> {
+        if now < unlock_time(year, day) {
+            return Err(format!("Day {} of {} has not unlocked yet", day, year).into());
+        }
+        Ok(())
+    }
+
+    /// Downloads the input of `day` into `path`, unless it is there already
+    pub fn fetch_input(
+        &self,
+        year: i32,
+        day: u32,
+        path: &Path,
+        now: SystemTime,
+    ) -> Result<Fetch, Box<dyn Error>> {
+        if path.exists() {
+            return Ok(Fetch::Cached);
+        }
+        Self::check_unlocked(year, day, now)?;
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let input = match self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session()?))
+            .call()
+        {
+            Ok(response) => response.into_string()?,
+            Err(ureq::Error::Status(400, _)) => {
+                return Err(format!("{}: the session cookie was rejected", url).into())
+            }
+            Err(ureq::Error::Status(code, _)) => {
+                return Err(format!("{}: HTTP {}", url, code).into())
+            }
+            Err(e) => return Err(format!("{}: {}", url, e).into()),
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, input).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(Fetch::Downloaded)
+    }
+
+    /// Posts an answer, returning the page the site responded with
+    pub fn submit_answer(
+        &self,
+        year: i32,
+        day: u32,
+        part: u8,
+        answer: &str,
+        now: SystemTime,
+    ) -> Result<String, Box<dyn Error>> {
+        Self::check_unlocked(year, day, now)?;
+
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        match self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session()?))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+        {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(code, _)) => Err(format!("{}: HTTP {}", url, code).into()),
+            Err(e) => Err(format!("{}: {}", url, e).into()),
+        }
+    }
+}
+
+/// A stand-in for the site that answers a single request
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Answers one request with `status` and `body`, returning the base URL to
+    /// use and a handle with the request line, headers and body it received
+    pub fn serve_once(status: &str, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+                if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mock::serve_once;
+
+    fn client(base_url: String) -> Client {
+        Client::new(Config {
+            session: Some(String::from("cafe")),
+            base_url: Some(base_url),
+        })
+    }
+
+    fn temp_input(name: &str) -> std::path::PathBuf {
+        let path = env::temp_dir().join(format!("aoc-{}-{}.txt", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        let unlock = unlock_time(2022, 1).duration_since(UNIX_EPOCH).unwrap();
+        assert_eq!(unlock.as_secs(), 1669870800);
+        let unlock = unlock_time(2024, 25).duration_since(UNIX_EPOCH).unwrap();
+        assert_eq!(unlock.as_secs(), 1735102800);
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, server) = serve_once("200 OK", "1000\n2000\n");
+        let path = temp_input("fetch");
+        let fetch = client(base_url).fetch_input(2022, 1, &path, SystemTime::now());
+
+        assert_eq!(fetch.unwrap(), Fetch::Downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=cafe"));
+
+        // Cached inputs are not downloaded again, so no server is needed
+        let fetch = client(String::from("http://127.0.0.1:1")).fetch_input(
+            2022,
+            1,
+            &path,
+            SystemTime::now(),
+        );
+        assert_eq!(fetch.unwrap(), Fetch::Cached);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (base_url, server) = serve_once("400 Bad Request", "Please log in");
+        let path = temp_input("fetch-errors");
+        let error = client(base_url)
+            .fetch_input(2022, 2, &path, SystemTime::now())
+            .unwrap_err();
+        assert!(error.to_string().contains("session cookie was rejected"));
+        server.join().unwrap();
+        assert!(!path.exists());
+
+        let before_unlock = unlock_time(2022, 3) - Duration::from_secs(1);
+        let error = client(String::from("http://127.0.0.1:1"))
+            .fetch_input(2022, 3, &path, before_unlock)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Day 3 of 2022 has not unlocked yet");
+    }
+}
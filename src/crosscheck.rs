@@ -0,0 +1,159 @@
+use std::time::Duration;
+
+use aoc_core::{
+    pool,
+    registry::Registration,
+    runner::{self, outcomes, InputSource, Outcome},
+};
+
+/// Implementations of a day that gave different outcomes for the same part of
+/// the same input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub input: String,
+    pub part: u8,
+    /// What every implementation that solves the part gave
+    pub outcomes: Vec<(&'static str, Outcome)>,
+}
+
+/// The disagreements between the `outcomes` of the implementations on `input`.
+/// Those without an answer to a part, like a naive version of only the first
+/// one, are left out of it.
+pub fn compare(input: &str, outcomes: &[(&'static str, [Outcome; 2])]) -> Vec<Disagreement> {
+    (1..=2)
+        .filter_map(|part| {
+            let given: Vec<(&'static str, Outcome)> = outcomes
+                .iter()
+                .map(|(name, outcomes)| (*name, outcomes[part as usize - 1].clone()))
+                .filter(|(_, outcome)| *outcome != Outcome::Answer(None))
+                .collect();
+            let agree = given.windows(2).all(|pair| pair[0].1 == pair[1].1);
+            (!agree).then(|| Disagreement {
+                input: input.to_string(),
+                part,
+                outcomes: given,
+            })
+        })
+        .collect()
+}
+
+/// Runs every implementation on every source, in parallel, and compares them
+pub fn run(
+    implementations: &[&'static Registration],
+    sources: &[InputSource],
+    timeout: Option<Duration>,
+) -> Vec<Disagreement> {
+    let runs: Vec<(&InputSource, &'static Registration)> = sources
+        .iter()
+        .flat_map(|source| implementations.iter().map(move |&r| (source, r)))
+        .collect();
+    let mut results = pool::map_ordered(&runs, pool::default_jobs(), |&(source, r)| {
+        (
+            r.name,
+            outcomes(runner::solve_with(r, None, source, &[], timeout)),
+        )
+    })
+    .into_iter();
+
+    let mut disagreements = Vec::new();
+    for source in sources {
+        let input = source.describe(implementations[0].year, implementations[0].day);
+        let outcomes: Vec<_> = results.by_ref().take(implementations.len()).collect();
+        disagreements.extend(compare(&input, &outcomes));
+    }
+    disagreements
+}
+
+/// The disagreements, one per line, and how many there were
+pub fn report(names: &[&str], inputs: usize, disagreements: &[Disagreement]) -> String {
+    let width = disagreements
+        .iter()
+        .map(|d| d.input.len())
+        .max()
+        .unwrap_or(0);
+    let mut report = String::new();
+    for d in disagreements {
+        let outcomes: Vec<String> = d
+            .outcomes
+            .iter()
+            .map(|(name, outcome)| format!("{} {}", name, outcome))
+            .collect();
+        report.push_str(&format!(
+            "{:<width$}  part {}: {}\n",
+            d.input,
+            d.part,
+            outcomes.join(", ")
+        ));
+    }
+    let count = match disagreements.len() {
+        0 => String::from("No disagreements"),
+        1 => String::from("1 disagreement"),
+        n => format!("{} disagreements", n),
+    };
+    report.push_str(&format!(
+        "{} between {} on {} input{}\n",
+        count,
+        names.join(", "),
+        inputs,
+        if inputs == 1 { "" } else { "s" }
+    ));
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::solver::{Answer, Failure};
+
+    const INPUT: &str = "examples/2022/day15/example.txt";
+
+    fn answer(n: i128) -> Outcome {
+        Outcome::Answer(Some(Answer::Integer(n)))
+    }
+
+    #[test]
+    fn test_compare() {
+        let agreeing = [
+            ("main", [answer(26), answer(56000011)]),
+            ("naive", [answer(26), Outcome::Answer(None)]),
+        ];
+        assert!(compare(INPUT, &agreeing).is_empty());
+
+        let panic = Outcome::Failure(Failure::Panic(String::from("overflow")));
+        let disagreeing = [
+            ("main", [answer(26), answer(56000011)]),
+            ("naive", [answer(27), Outcome::Answer(None)]),
+            ("other", [panic.clone(), answer(56000011)]),
+        ];
+        assert_eq!(
+            compare(INPUT, &disagreeing),
+            vec![Disagreement {
+                input: String::from(INPUT),
+                part: 1,
+                outcomes: vec![
+                    ("main", answer(26)),
+                    ("naive", answer(27)),
+                    ("other", panic)
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let disagreements = vec![Disagreement {
+            input: String::from(INPUT),
+            part: 1,
+            outcomes: vec![("main", answer(26)), ("naive", answer(27))],
+        }];
+        let expected = "\
+examples/2022/day15/example.txt  part 1: main 26, naive 27
+1 disagreement between main, naive on 2 inputs
+";
+        assert_eq!(report(&["main", "naive"], 2, &disagreements), expected);
+        assert_eq!(
+            report(&["main", "naive"], 1, &[]),
+            "No disagreements between main, naive on 1 input\n"
+        );
+    }
+}
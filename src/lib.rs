@@ -0,0 +1,31 @@
+//! Solutions to Advent of Code, one module per year and day.
+//!
+//! Every `solutions::yYYYY::dayN::Problem` registers itself with the
+//! [`aoc_core::registry`], so depending on this crate is enough for the runner
+//! to find them.
+
+pub mod solutions;
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{registry, solver::Answer};
+
+    #[test]
+    fn test_days() {
+        assert!(registry::years().contains(&2022));
+        let days = registry::days(2022);
+        assert!((1..=16).all(|day| days.contains(&day)));
+    }
+
+    #[test]
+    fn test_run() {
+        let solver = (registry::get(2022, 2).unwrap().solver)();
+        let results = solver.run(2022, 2, String::from("A Y\nB X\nC Z"), Some(2));
+        let answers: Vec<_> = results
+            .unwrap()
+            .into_iter()
+            .map(|r| (r.part, r.answer))
+            .collect();
+        assert_eq!(answers, vec![(2, Some(Answer::Integer(12)))]);
+    }
+}
@@ -1,14 +1,401 @@
-mod grid;
-mod solutions;
-mod solver;
-use std::env;
+mod cli;
+mod client;
+mod crosscheck;
+mod scaffold;
+mod submit;
+mod watch;
+use std::{
+    env,
+    path::Path,
+    process,
+    time::{Duration, SystemTime},
+};
+
+use aoc_core::{
+    answers::{Answers, Check, ANSWERS_PATH},
+    bench::BenchOptions,
+    generate, pool, registry,
+    report::{self, Format, MatrixCell, Record},
+    runner::{self, InputSource},
+    solver::Answer,
+};
+// Linked for the solvers it registers
+use aoc_solutions as _;
+use cli::{Command, Days, RunArgs};
+use client::{Client, Config, Fetch, CONFIG_PATH};
+use scaffold::Template;
+use submit::{SubmissionLog, Verdict, SUBMISSIONS_PATH};
 
 fn main() {
-    let day: u32 = env::args()
-        .nth(1)
-        .expect("Day is required")
-        .parse()
-        .unwrap();
-    println!("Running day {}", day);
-    solutions::solve(day);
+    let command = cli::parse(env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, cli::USAGE);
+        process::exit(2);
+    });
+
+    match command {
+        Command::Run(args) => run(args),
+        Command::List => list(),
+        Command::Fetch { day, year } => fetch(day, year),
+        Command::Submit { day, part, year } => submit(day, part, year),
+        Command::New {
+            day,
+            year,
+            template,
+        } => new(day, year, template),
+        Command::Verify { record, timeout } => verify(record, timeout),
+        Command::Bench(args, options) => bench(args, options),
+        Command::Watch { day, year } => watch(day, year),
+        Command::Matrix {
+            year,
+            jobs,
+            timeout,
+        } => matrix(year, jobs, timeout),
+        Command::Crosscheck { day, year, timeout } => crosscheck(day, year, timeout),
+        Command::Gen {
+            day,
+            year,
+            seed,
+            size,
+        } => gen(day, year, seed, size),
+    }
+}
+
+/// Warns once about the timed out parts whose workers are still running
+fn warn_leftover_workers() {
+    let leftover = runner::leftover_workers();
+    if !leftover.is_empty() {
+        eprintln!(
+            "warning: {} timed out and can't be stopped, left running until the run ends",
+            leftover.join(", ")
+        );
+    }
+}
+
+fn selected_days(year: i32, days: &Days) -> Vec<u32> {
+    match days {
+        Days::All => registry::days(year),
+        Days::Range(range) => range.clone().collect(),
+    }
+}
+
+fn run(args: RunArgs) {
+    let days = selected_days(args.year, &args.days);
+    let parts: Vec<u8> = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let jobs = args.jobs.unwrap_or_else(pool::default_jobs);
+    let outcomes = pool::map_ordered(&days, jobs, |&day| {
+        runner::solve(
+            args.year,
+            day,
+            args.part,
+            &args.source,
+            &args.params,
+            args.timeout,
+        )
+    });
+
+    let mut results = Vec::new();
+    let mut records = Vec::new();
+    for (day, outcome) in days.into_iter().zip(outcomes) {
+        let input = args.source.describe(args.year, day);
+        match outcome {
+            Some(Ok(day_results)) => {
+                records.extend(day_results.iter().map(|r| Record::new(r, &input)));
+                results.extend(day_results);
+            }
+            Some(Err(e)) => {
+                eprintln!("error: {}", e);
+                let error = e.to_string();
+                records.extend(
+                    parts
+                        .iter()
+                        .map(|&p| Record::error(args.year, day, p, &input, &error)),
+                );
+            }
+            None => eprintln!("Day {} of {} has not been solved yet", day, args.year),
+        }
+    }
+
+    match args.format {
+        Format::Text if !results.is_empty() => print!("{}", report::summary_table(&results)),
+        Format::Text => {}
+        Format::Json => print!("{}", report::json(&records)),
+        Format::Csv => print!("{}", report::csv(&records)),
+    }
+    warn_leftover_workers();
+}
+
+fn list() {
+    println!("{:>4}  {:>3}  Input", "Year", "Day");
+    for year in registry::years() {
+        for day in registry::days(year) {
+            let path = runner::input_path(year, day);
+            let input = match Path::new(&path).exists() {
+                true => path,
+                false => String::from("-"),
+            };
+            println!("{:>4}  {:>3}  {}", year, day, input);
+        }
+    }
+}
+
+fn fetch(day: u32, year: i32) {
+    let path = runner::input_path(year, day);
+    let fetched = Config::load(CONFIG_PATH).and_then(|config| {
+        Client::new(config).fetch_input(year, day, Path::new(&path), SystemTime::now())
+    });
+    match fetched {
+        Ok(Fetch::Cached) => println!("{} is already there", path),
+        Ok(Fetch::Downloaded) => println!("Saved {}", path),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn submit(day: u32, part: u8, year: i32) {
+    let fail = |e: &dyn std::fmt::Display| -> ! {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    };
+
+    let answer = match runner::solve(year, day, Some(part), &InputSource::Default, &[], None) {
+        Some(Ok(results)) => results.into_iter().find_map(|r| match r.failure {
+            Some(failure) => fail(&format!("Day {} part {} {}", day, part, failure)),
+            None => r.answer,
+        }),
+        Some(Err(e)) => fail(&e),
+        None => fail(&format!("Day {} of {} has not been solved yet", day, year)),
+    };
+    let Some(answer) = answer else {
+        fail(&format!(
+            "Day {} part {} has no answer to submit",
+            day, part
+        ));
+    };
+    if let Answer::Grid { rows, .. } = &answer {
+        fail(&format!(
+            "The answer is a picture, read it and submit by hand:\n{}",
+            rows
+        ));
+    }
+    let answer = answer.to_string();
+    println!("Day {} part {}: {}", day, part, answer);
+
+    let mut log = SubmissionLog::load(SUBMISSIONS_PATH).unwrap_or_else(|e| fail(&e));
+    let client = Config::load(CONFIG_PATH)
+        .map(Client::new)
+        .unwrap_or_else(|e| fail(&e));
+    let outcome = submit::submit(
+        &client,
+        &mut log,
+        (year, day, part),
+        &answer,
+        SystemTime::now(),
+    )
+    .unwrap_or_else(|e| fail(&e));
+    log.save(SUBMISSIONS_PATH).unwrap_or_else(|e| fail(&e));
+
+    match outcome.wait {
+        Some(wait) => println!("{}, {} left to wait", outcome.verdict, wait),
+        None => println!("{}", outcome.verdict),
+    }
+    if outcome.verdict != Verdict::Right {
+        process::exit(1);
+    }
+}
+
+fn new(day: u32, year: i32, template: Template) {
+    match scaffold::create(Path::new("."), year, day, template) {
+        Ok(created) => {
+            for path in created {
+                println!("Created {}", path.display());
+            }
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn verify(record: bool, timeout: Option<Duration>) {
+    let mut answers = Answers::load(ANSWERS_PATH).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    });
+
+    let days: Vec<(i32, u32)> = registry::years()
+        .into_iter()
+        .flat_map(|year| registry::days(year).into_iter().map(move |day| (year, day)))
+        .collect();
+    let outcomes = pool::map_ordered(&days, pool::default_jobs(), |&(year, day)| {
+        runner::solve(year, day, None, &InputSource::Default, &[], timeout).unwrap()
+    });
+
+    let mut checks = Vec::new();
+    for ((year, day), outcome) in days.into_iter().zip(outcomes) {
+        match outcome {
+            Ok(results) => {
+                for result in results {
+                    if record {
+                        answers.record(&result);
+                    }
+                    checks.push(Check::new(&result, &answers));
+                }
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                checks.extend((1..=2).map(|part| Check::error(year, day, part, &answers)));
+            }
+        }
+    }
+
+    if record {
+        if let Err(e) = answers.save(ANSWERS_PATH) {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+
+    print!("{}", report::verify_table(&checks));
+    warn_leftover_workers();
+    if checks.iter().any(|c| c.status.is_failure()) {
+        process::exit(1);
+    }
+}
+
+fn bench(args: RunArgs, options: BenchOptions) {
+    let mut results = Vec::new();
+    for day in selected_days(args.year, &args.days) {
+        match runner::bench(
+            args.year,
+            day,
+            args.part,
+            &args.source,
+            &args.params,
+            &options,
+        ) {
+            Some(Ok(day_results)) => results.extend(day_results),
+            Some(Err(e)) => eprintln!("error: {}", e),
+            None => eprintln!("Day {} of {} has not been solved yet", day, args.year),
+        }
+    }
+
+    if !results.is_empty() {
+        print!("{}", report::bench_table(&results));
+    }
+}
+
+fn watch(day: u32, year: i32) {
+    if registry::get(year, day).is_none() {
+        eprintln!("error: Day {} of {} has not been solved yet", day, year);
+        process::exit(1);
+    }
+    println!("Watching day {} of {}, press Ctrl-C to stop\n", day, year);
+    watch::watch(year, day);
+}
+
+fn matrix(year: i32, jobs: Option<usize>, timeout: Option<Duration>) {
+    let members = runner::members(Path::new(runner::INPUTS_DIR), year);
+    if members.is_empty() {
+        eprintln!(
+            "No team inputs for {}, expected {}",
+            year,
+            runner::member_input_path("<member>", year, 1)
+        );
+        process::exit(1);
+    }
+
+    let days = registry::days(year);
+    let runs: Vec<(u32, &String)> = days
+        .iter()
+        .flat_map(|&day| members.iter().map(move |member| (day, member)))
+        .collect();
+    let jobs = jobs.unwrap_or_else(pool::default_jobs);
+    let mut cells = pool::map_ordered(&runs, jobs, |&(day, member)| {
+        let path = runner::member_input_path(member, year, day);
+        if !Path::new(&path).exists() {
+            return MatrixCell::NoInput;
+        }
+        match runner::solve(year, day, None, &InputSource::File(path), &[], timeout) {
+            Some(Ok(results)) => MatrixCell::Solved(results),
+            Some(Err(e)) => MatrixCell::Error(e.to_string()),
+            None => unreachable!("day {} is registered", day),
+        }
+    })
+    .into_iter();
+
+    let rows: Vec<(u32, Vec<MatrixCell>)> = days
+        .into_iter()
+        .map(|day| (day, cells.by_ref().take(members.len()).collect()))
+        // Days nobody has an input for yet
+        .filter(|(_, cells): &(u32, Vec<MatrixCell>)| {
+            cells.iter().any(|c| *c != MatrixCell::NoInput)
+        })
+        .collect();
+    print!("{}", report::matrix_table(&members, &rows));
+    warn_leftover_workers();
+
+    let failed = rows
+        .iter()
+        .flat_map(|(_, cells)| cells)
+        .any(|cell| match cell {
+            MatrixCell::NoInput => false,
+            MatrixCell::Error(_) => true,
+            MatrixCell::Solved(results) => results.iter().any(|r| r.failure.is_some()),
+        });
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn crosscheck(day: u32, year: i32, timeout: Option<Duration>) {
+    let implementations = registry::implementations(year, day);
+    if implementations.is_empty() {
+        eprintln!("error: Day {} of {} has not been solved yet", day, year);
+        process::exit(1);
+    }
+    if implementations.len() < 2 {
+        eprintln!(
+            "error: Day {} of {} has a single implementation, nothing to crosscheck",
+            day, year
+        );
+        process::exit(1);
+    }
+    let sources = runner::sources(year, day);
+    if sources.is_empty() {
+        eprintln!(
+            "error: Day {} of {} has no input or examples, expected {}",
+            day,
+            year,
+            runner::input_path(year, day)
+        );
+        process::exit(1);
+    }
+
+    let disagreements = crosscheck::run(&implementations, &sources, timeout);
+    let names: Vec<&str> = implementations.iter().map(|r| r.name).collect();
+    print!(
+        "{}",
+        crosscheck::report(&names, sources.len(), &disagreements)
+    );
+    warn_leftover_workers();
+    if !disagreements.is_empty() {
+        process::exit(1);
+    }
+}
+
+fn gen(day: u32, year: i32, seed: u64, size: Option<usize>) {
+    match generate::generate(year, day, seed, size) {
+        Some(input) => println!("{}", input),
+        None => {
+            eprintln!("error: Day {} of {} has no input generator", day, year);
+            process::exit(1);
+        }
+    }
 }
@@ -0,0 +1,135 @@
+use aoc_core::fixtures::{self, EXAMPLES_DIR};
+use std::{
+    error::Error,
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Template {
+    /// One item per line
+    Lines,
+    /// A `Grid<char>` of the input
+    Grid,
+    /// An adjacency map from lines like `a: b c`
+    Graph,
+}
+
+impl Template {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "lines" => Some(Template::Lines),
+            "grid" => Some(Template::Grid),
+            "graph" => Some(Template::Graph),
+            _ => None,
+        }
+    }
+
+    fn source(&self) -> &'static str {
+        match self {
+            Template::Lines => include_str!("templates/lines.rs"),
+            Template::Grid => include_str!("templates/grid.rs"),
+            Template::Graph => include_str!("templates/graph.rs"),
+        }
+    }
+
+    pub fn render(&self, year: i32, day: u32) -> String {
+        self.source()
+            .replace("__YEAR__", &year.to_string())
+            .replace("__DAY__", &day.to_string())
+    }
+}
+
+pub fn day_path(root: &Path, year: i32, day: u32) -> PathBuf {
+    root.join(format!("src/solutions/y{}/day{}.rs", year, day))
+}
+
+pub fn example_path(root: &Path, year: i32, day: u32) -> PathBuf {
+    fixtures::input_path(&root.join(EXAMPLES_DIR), year, day, fixtures::DEFAULT_NAME)
+}
+
+/// Expected answers of a new fixture, to fill in from the puzzle text
+const EXPECTED_STUB: &str = "# part1 = \"\"\n# part2 = \"\"\n";
+
+/// Writes `contents` to `path` unless it already exists, returning whether it
+/// was created
+fn create_new(path: &Path, contents: &str) -> Result<bool, Box<dyn Error>> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(mut file) => {
+            file.write_all(contents.as_bytes())?;
+            Ok(true)
+        }
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(format!("{}: {}", path.display(), e).into()),
+    }
+}
+
+/// Creates `src/solutions/yYYYY/dayN.rs` from `template` and an empty example
+/// fixture with its expected answers for it, returning the files created. An
+/// existing day is never overwritten, an existing fixture is kept as is.
+pub fn create(
+    root: &Path,
+    year: i32,
+    day: u32,
+    template: Template,
+) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let day_file = day_path(root, year, day);
+    if !create_new(&day_file, &template.render(year, day))? {
+        return Err(format!("{} already exists", day_file.display()).into());
+    }
+
+    let mut created = vec![day_file];
+    let example = example_path(root, year, day);
+    let expected = example.with_extension("toml");
+    if create_new(&example, "")? {
+        created.push(example);
+    }
+    if create_new(&expected, EXPECTED_STUB)? {
+        created.push(expected);
+    }
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_render() {
+        let source = Template::Grid.render(2023, 21);
+        assert!(source.contains("register!(2023, 21, Problem);"));
+        assert!(!source.contains("__DAY__") && !source.contains("__YEAR__"));
+    }
+
+    #[test]
+    fn test_create() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        let created = create(&root, 2023, 21, Template::Lines).unwrap();
+        let example = example_path(&root, 2023, 21);
+        assert_eq!(
+            created,
+            vec![
+                day_path(&root, 2023, 21),
+                example.clone(),
+                example.with_extension("toml")
+            ]
+        );
+        assert_eq!(created[1], root.join("examples/2023/day21/example.txt"));
+
+        fs::write(day_path(&root, 2023, 21), "solved").unwrap();
+        assert!(create(&root, 2023, 21, Template::Graph).is_err());
+        assert_eq!(
+            fs::read_to_string(day_path(&root, 2023, 21)).unwrap(),
+            "solved"
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,61 @@
+use aoc_core::generate::{InputGenerator, Rng};
+use aoc_core::parse::{parse_token, ParseError};
+use aoc_core::solver::Solver;
+use aoc_core::{register, register_generator};
+use std::cmp::Reverse;
+
+pub struct Problem;
+
+register!(2022, 1, Problem);
+register_generator!(2022, 1, Problem);
+
+impl Solver for Problem {
+    type Input<'a> = Vec<u128>;
+    type Prepared<'a> = ();
+    type Output1 = u128;
+    type Output2 = u128;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        raw_input
+            .split("\n\n")
+            .map(|lines| {
+                lines
+                    .lines()
+                    .filter(|x| !x.is_empty())
+                    .map(|x| parse_token::<u128>(raw_input, x, "a calorie count"))
+                    .sum()
+            })
+            .collect()
+    }
+
+    fn solve_first(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output1> {
+        Some(*input.iter().max().unwrap())
+    }
+
+    fn solve_second(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output2> {
+        let mut calories: Vec<u128> = input.clone();
+
+        calories.sort_unstable_by_key(|x| Reverse(*x));
+
+        Some(calories.iter().take(3).sum())
+    }
+}
+
+/// The snacks of `size` elves
+impl InputGenerator for Problem {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let elves: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let snacks: Vec<String> = (0..rng.range(1..=15))
+                    .map(|_| rng.range(1000..=60000).to_string())
+                    .collect();
+                snacks.join("\n")
+            })
+            .collect();
+        elves.join("\n\n")
+    }
+
+    fn real_size(&self) -> usize {
+        250
+    }
+}
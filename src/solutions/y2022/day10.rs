@@ -0,0 +1,197 @@
+use aoc_core::generate::{InputGenerator, Rng};
+use aoc_core::ocr;
+use aoc_core::parse::{parse_token, ParseError};
+use aoc_core::solver::{Answer, Solver};
+use aoc_core::{register, register_generator};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Addx(isize),
+}
+
+pub struct Problem;
+
+register!(2022, 10, Problem);
+register_generator!(2022, 10, Problem);
+
+impl Solver for Problem {
+    type Input<'a> = Vec<Instruction>;
+    type Prepared<'a> = ();
+    type Output1 = isize;
+    type Output2 = Answer;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        raw_input
+            .lines()
+            .map(|l| match l.split_once(' ') {
+                None if l == "noop" => Ok(Instruction::Noop),
+                Some(("addx", v)) => Ok(Instruction::Addx(parse_token(raw_input, v, "a number")?)),
+                _ => Err(ParseError::at(raw_input, l, "`noop` or `addx N`")),
+            })
+            .collect()
+    }
+
+    fn solve_first(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output1> {
+        let mut x = 1;
+        let mut strengths = Vec::<isize>::new();
+        let mut cycle = 1;
+
+        for instruction in input.iter() {
+            cycle += 1;
+
+            if (cycle + 20) % 40 == 0 {
+                strengths.push(cycle * x);
+            }
+
+            match instruction {
+                Instruction::Noop => {}
+                Instruction::Addx(v) => {
+                    cycle += 1;
+                    x += v;
+                    if (cycle + 20) % 40 == 0 {
+                        strengths.push(cycle * x);
+                    }
+                }
+            }
+        }
+
+        Some(strengths.into_iter().sum())
+    }
+
+    fn solve_second(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output2> {
+        let mut x = 1;
+        let mut cycle = 1_isize;
+        let mut buf = vec!['.'; 40 * 6];
+        draw(&mut buf, cycle, x);
+
+        for instruction in input.iter() {
+            draw(&mut buf, cycle, x);
+            match instruction {
+                Instruction::Noop => {}
+                Instruction::Addx(v) => {
+                    cycle += 1;
+                    draw(&mut buf, cycle, x);
+                    x += v;
+                }
+            }
+            cycle += 1;
+        }
+
+        let rows: Vec<String> = buf.chunks(40).map(|row| row.iter().collect()).collect();
+        // The screen shows letters, unless it can't be read
+        let screen = rows.join("\n");
+        match ocr::read(&screen, '#') {
+            Ok(letters) => Some(Answer::Text(letters)),
+            Err(error) => Some(Answer::Grid {
+                rows: screen,
+                note: Some(format!("Not read as letters, {}", error)),
+            }),
+        }
+    }
+}
+
+/// A program drawing 8 random letters, the screen is always full so `size` is
+/// ignored
+impl InputGenerator for Problem {
+    fn generate(&self, rng: &mut Rng, _size: usize) -> String {
+        // The first two pixels are always lit, as `x` starts at 1
+        let first: Vec<char> = ocr::letters()
+            .filter(|&l| ocr::draw(&l.to_string()).unwrap().starts_with("##"))
+            .collect();
+        let letters: Vec<char> = ocr::letters().collect();
+        let mut text = rng.choose(&first).to_string();
+        for _ in 1..8 {
+            text.push(*rng.choose(&letters));
+        }
+        // 8 letters and the blank columns between them leave one at the end
+        let rows: Vec<String> = ocr::draw(&text)
+            .unwrap()
+            .lines()
+            .map(|row| format!("{}.", row))
+            .collect();
+        program(&rows.join("\n"))
+    }
+
+    fn real_size(&self) -> usize {
+        8
+    }
+}
+
+/// A program drawing `screen`, with `x` set for every pair of cycles. The
+/// first pair is drawn with the initial `x` of 1.
+fn program(screen: &str) -> String {
+    let pixels: Vec<bool> = screen
+        .lines()
+        .flat_map(|l| l.chars())
+        .map(|c| c == '#')
+        .collect();
+    let targets: Vec<isize> = pixels
+        .chunks(2)
+        .enumerate()
+        .map(|(i, pair)| {
+            let col = (i * 2 % 40) as isize;
+            match (pair[0], pair[1]) {
+                (true, true) => col,
+                (true, false) => col - 1,
+                (false, true) => col + 2,
+                (false, false) => col + 4,
+            }
+        })
+        .collect();
+
+    let mut x = 1;
+    let mut lines = Vec::new();
+    for target in targets.into_iter().skip(1) {
+        match target - x {
+            0 => lines.extend(["noop", "noop"].map(String::from)),
+            dx => lines.push(format!("addx {}", dx)),
+        }
+        x = target;
+    }
+    lines.push(String::from("noop"));
+    lines.join("\n")
+}
+
+fn draw(buf: &mut [char], cycle: isize, x: isize) {
+    let col = (cycle - 1) % 40;
+    if x.abs_diff(col) < 2 {
+        buf[cycle as usize - 1] = '#';
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCREEN: &str = "\
+####.#..#.####.####.####.#..#..##..####.
+#....#..#....#.#.......#.#..#.#..#....#.
+###..####...#..###....#..####.#......#..
+#....#..#..#...#.....#...#..#.#.....#...
+#....#..#.#....#....#....#..#.#..#.#....
+####.#..#.####.#....####.#..#..##..####.";
+
+    #[test]
+    fn test_second_letters() {
+        let input = Problem {}.parse_input(&program(SCREEN)).unwrap();
+        let result = Problem {}.solve_second(&input, &());
+        assert_eq!(result, Some(Answer::from("EHZFZHCZ")));
+    }
+
+    #[test]
+    fn test_second_unknown_letter() {
+        // The E loses the end of its top bar
+        let screen = SCREEN.replacen("####.", "###..", 1);
+        let input = Problem {}.parse_input(&program(&screen)).unwrap();
+        let result = Problem {}.solve_second(&input, &());
+        let note = "Not read as letters, unknown letter 1:\n###.\n#...\n###.\n#...\n#...\n####";
+        assert_eq!(
+            result,
+            Some(Answer::Grid {
+                rows: screen,
+                note: Some(String::from(note)),
+            })
+        );
+    }
+}
@@ -0,0 +1,334 @@
+use std::cmp::Reverse;
+
+use aoc_core::generate::{InputGenerator, Rng};
+use aoc_core::parse::{parse_token, ParseError};
+use aoc_core::solver::{parse_param, Solver};
+use aoc_core::{register, register_generator};
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Add(usize),
+    Mult(usize),
+    Square,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monke {
+    items: Vec<usize>,
+    operation: Operation,
+    test_divisible: usize,
+    next_target: (usize, usize),
+}
+
+pub struct Problem {
+    /// Rounds played in part 1, while worry levels are divided by 3
+    pub rounds: usize,
+    /// Rounds played in part 2
+    pub long_rounds: usize,
+}
+
+impl Default for Problem {
+    fn default() -> Self {
+        Self {
+            rounds: 20,
+            long_rounds: 10000,
+        }
+    }
+}
+
+register!(2022, 11, Problem::default());
+register_generator!(2022, 11, Problem::default());
+
+fn last_word(line: &str) -> &str {
+    line.split_ascii_whitespace().last().unwrap_or(line)
+}
+
+fn last_number_in_line(input: &str, line: &str) -> Result<usize, ParseError> {
+    parse_token(input, last_word(line), "a number")
+}
+
+impl Solver for Problem {
+    type Input<'a> = Vec<Monke>;
+    type Prepared<'a> = ();
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let count = raw_input.split("\n\n").count();
+        raw_input
+            .split("\n\n")
+            .map(|raw_monke| {
+                let lines: Vec<&str> = raw_monke.lines().map(|l| l.trim()).collect();
+                let line = |i: usize, expected: &str| {
+                    lines.get(i).copied().ok_or_else(|| {
+                        ParseError::at(raw_input, &raw_monke[raw_monke.len()..], expected)
+                    })
+                };
+
+                let raw_items = line(1, "the starting items")?;
+                let items = raw_items
+                    .split_once(": ")
+                    .ok_or_else(|| ParseError::at(raw_input, raw_items, "the starting items"))?
+                    .1
+                    .split(", ")
+                    .map(|n| parse_token(raw_input, n, "an item"))
+                    .collect::<Result<_, _>>()?;
+
+                let raw_op = line(2, "an operation")?;
+                let operation = match raw_op.split_once("= ") {
+                    Some((_, "old * old")) => Operation::Square,
+                    Some((_, op)) => match (op.split_once("+ "), op.split_once("* ")) {
+                        (Some((_, x)), _) => Operation::Add(parse_token(raw_input, x, "a number")?),
+                        (_, Some((_, x))) => {
+                            Operation::Mult(parse_token(raw_input, x, "a number")?)
+                        }
+                        _ => return Err(ParseError::at(raw_input, op, "an addition or product")),
+                    },
+                    None => return Err(ParseError::at(raw_input, raw_op, "an operation")),
+                };
+
+                let raw_test = line(3, "a test")?;
+                // Nothing is divisible by 0, and part 2 keeps worries modulo it
+                let test_divisible = match last_number_in_line(raw_input, raw_test)? {
+                    0 => {
+                        return Err(ParseError::at(
+                            raw_input,
+                            last_word(raw_test),
+                            "a divisor above 0",
+                        ))
+                    }
+                    n => n,
+                };
+                // Throwing to a monkey that isn't there would lose the item
+                let target = |i: usize, expected: &str| {
+                    let raw_target = line(i, expected)?;
+                    match last_number_in_line(raw_input, raw_target)? {
+                        target if target < count => Ok(target),
+                        _ => Err(ParseError::at(
+                            raw_input,
+                            raw_target,
+                            format!("a monkey below {}", count),
+                        )),
+                    }
+                };
+                let next_target = (target(4, "a true target")?, target(5, "a false target")?);
+
+                Ok(Monke {
+                    items,
+                    operation,
+                    test_divisible,
+                    next_target,
+                })
+            })
+            .collect()
+    }
+
+    fn solve_first(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output1> {
+        let mut monkes = input.to_vec();
+        let mut inspections = vec![0; monkes.len()];
+
+        for _ in 0..self.rounds {
+            monke_round(&mut monkes, &mut inspections, |x| x / 3);
+        }
+        inspections.sort_unstable_by_key(|x| Reverse(*x));
+
+        Some(inspections.into_iter().take(2).product())
+    }
+
+    fn solve_second(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output2> {
+        let mut monkes = input.to_vec();
+        let mut inspections = vec![0; monkes.len()];
+        let modulo: usize = monkes.iter().map(|m| m.test_divisible).product();
+
+        for _ in 0..self.long_rounds {
+            monke_round(&mut monkes, &mut inspections, |x| x % modulo);
+        }
+        inspections.sort_unstable_by_key(|x| Reverse(*x));
+
+        Some(inspections.into_iter().take(2).product())
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "rounds" => self.rounds = parse_param(name, value)?,
+            "long_rounds" => self.long_rounds = parse_param(name, value)?,
+            _ => return Err(format!("unknown parameter {}", name)),
+        }
+        Ok(())
+    }
+}
+
+fn monke_round(monkes: &mut [Monke], inspections: &mut [usize], worry_fn: impl Fn(usize) -> usize) {
+    for monke_i in 0..monkes.len() {
+        let monke = monkes[monke_i].clone();
+        for item in monke.items {
+            inspections[monke_i] += 1;
+
+            let new_item = worry_fn(match monke.operation {
+                Operation::Add(x) => item + x,
+                Operation::Mult(x) => item * x,
+                Operation::Square => item * item,
+            });
+
+            // to other monke
+            if new_item.is_multiple_of(monke.test_divisible) {
+                monkes[monke.next_target.0].items.push(new_item);
+            } else {
+                monkes[monke.next_target.1].items.push(new_item);
+            }
+        }
+        monkes[monke_i].items.clear();
+    }
+}
+
+/// Divisors of the tests, distinct so their product, which part 2 keeps worry
+/// levels below, can still be squared
+const PRIMES: [usize; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+/// A notebook of `size` monkeys, at least 2 and at most 9. Like in real ones,
+/// a single monkey squares the worry level, as long as part 1 doesn't take a
+/// worry level past `usize::MAX`.
+impl InputGenerator for Problem {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        // Few monkeys pass items back and forth too often to multiply them,
+        // they fall back to additions only, which always fit
+        let monkes = (0..)
+            .map(|tries| random_monkes(rng, size.clamp(2, PRIMES.len()), tries < 100))
+            .find(|monkes| fits(monkes, self.rounds))
+            .unwrap();
+
+        let notebook: Vec<String> = monkes
+            .iter()
+            .enumerate()
+            .map(|(i, monke)| {
+                let items: Vec<String> = monke.items.iter().map(usize::to_string).collect();
+                let operation = match monke.operation {
+                    Operation::Add(x) => format!("old + {}", x),
+                    Operation::Mult(x) => format!("old * {}", x),
+                    Operation::Square => String::from("old * old"),
+                };
+                format!(
+                    "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+                    i,
+                    items.join(", "),
+                    operation,
+                    monke.test_divisible,
+                    monke.next_target.0,
+                    monke.next_target.1
+                )
+            })
+            .collect();
+        notebook.join("\n\n")
+    }
+
+    fn real_size(&self) -> usize {
+        8
+    }
+}
+
+fn random_monkes(rng: &mut Rng, count: usize, multiply: bool) -> Vec<Monke> {
+    let mut primes = PRIMES;
+    rng.shuffle(&mut primes);
+    let square = rng.below(count);
+
+    (0..count)
+        .map(|i| {
+            let operation = match multiply && i == square {
+                true => Operation::Square,
+                false if multiply && rng.one_in(3) => Operation::Mult(rng.range(2..=19)),
+                false => Operation::Add(rng.range(1..=8)),
+            };
+            let others: Vec<usize> = (0..count).filter(|&other| other != i).collect();
+            let if_true = *rng.choose(&others);
+            let others: Vec<usize> = match others.len() {
+                1 => others,
+                _ => others
+                    .into_iter()
+                    .filter(|&other| other != if_true)
+                    .collect(),
+            };
+            Monke {
+                items: (0..rng.range(1..=8)).map(|_| rng.range(50..=99)).collect(),
+                operation,
+                test_divisible: primes[i],
+                next_target: (if_true, *rng.choose(&others)),
+            }
+        })
+        .collect()
+}
+
+/// Whether `rounds` of part 1 keep every worry level within a `usize`, which
+/// [`monke_round`] doesn't check
+fn fits(monkes: &[Monke], rounds: usize) -> bool {
+    let mut monkes = monkes.to_vec();
+    for _ in 0..rounds {
+        for monke_i in 0..monkes.len() {
+            let items = std::mem::take(&mut monkes[monke_i].items);
+            for item in items {
+                let new_item = match monkes[monke_i].operation {
+                    Operation::Add(x) => item.checked_add(x),
+                    Operation::Mult(x) => item.checked_mul(x),
+                    Operation::Square => item.checked_mul(item),
+                };
+                let Some(new_item) = new_item.map(|x| x / 3) else {
+                    return false;
+                };
+                let (if_true, if_false) = monkes[monke_i].next_target;
+                let target = match new_item.is_multiple_of(monkes[monke_i].test_divisible) {
+                    true => if_true,
+                    false => if_false,
+                };
+                monkes[target].items.push(new_item);
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Monkey 0:\nStarting items: 79, 98";
+        let error = Problem::default().parse_input(input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 23));
+        assert_eq!(error.expected, "an operation");
+    }
+
+    #[test]
+    fn test_missing_target() {
+        let input = "\
+Monkey 0:
+  Starting items: 79
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 1
+    If false: throw to monkey 2
+
+Monkey 1:
+  Starting items: 54
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+        let error = Problem::default().parse_input(input).unwrap_err();
+        assert_eq!((error.line, error.column), (6, 5));
+        assert_eq!(error.expected, "a monkey below 2");
+    }
+
+    #[test]
+    fn test_zero_divisor() {
+        let input = "\
+Monkey 0:
+  Starting items: 79
+  Operation: new = old * 19
+  Test: divisible by 0
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+        let error = Problem::default().parse_input(input).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 22));
+        assert_eq!(error.expected, "a divisor above 0");
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_core::generate::{InputGenerator, Rng};
+use aoc_core::parse::ParseError;
+use aoc_core::solver::Solver;
+use aoc_core::{register, register_generator};
+use aoc_grid::{Coord, Grid, GridPoint, ORTHOGONAL_DIRS};
+
+fn shortest_route(grid: &Grid<char>, start: GridPoint, end: GridPoint) -> Option<usize> {
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    queue.push_back((start, 0));
+
+    while let Some((point, cost)) = queue.pop_front() {
+        if point == end {
+            return Some(cost);
+        }
+        let current_value = grid.get(&point).unwrap();
+        for dir in ORTHOGONAL_DIRS {
+            let candidate = GridPoint::new(point.x() + dir.0, point.y() + dir.1);
+            let candidate_value = grid.get(&candidate);
+            if candidate_value.is_some()
+                && (*current_value as usize + 1) >= *candidate_value.unwrap() as usize
+                && !visited.contains(&candidate)
+            {
+                visited.insert(candidate);
+                queue.push_back((candidate, cost + 1));
+            }
+        }
+    }
+    None
+}
+
+pub struct Problem;
+
+register!(2022, 12, Problem);
+register_generator!(2022, 12, Problem);
+
+impl Solver for Problem {
+    type Input<'a> = (Grid<char>, GridPoint, GridPoint);
+    type Prepared<'a> = ();
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let lines: Vec<&str> = raw_input.lines().collect();
+        let h = lines.len();
+        let w = lines
+            .first()
+            .ok_or_else(|| ParseError::end_of_input(raw_input, "a row of the heightmap"))?
+            .chars()
+            .count();
+        if let Some(l) = lines.iter().find(|l| l.chars().count() != w) {
+            return Err(ParseError::at(
+                raw_input,
+                l,
+                format!("a row of length {}", w),
+            ));
+        }
+        let mut start = None;
+        let mut end = None;
+        let mut cells = Vec::with_capacity(w * h);
+        for (y, line) in lines.iter().enumerate() {
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                let point = GridPoint::new(x as isize, y as isize);
+                cells.push(match c {
+                    'S' => {
+                        start = Some(point);
+                        'a'
+                    }
+                    'E' => {
+                        end = Some(point);
+                        'z'
+                    }
+                    'a'..='z' => c,
+                    _ => {
+                        return Err(ParseError::at(
+                            raw_input,
+                            &line[i..i + c.len_utf8()],
+                            "a height from a to z, `S` or `E`",
+                        ))
+                    }
+                });
+            }
+        }
+        let start = start.ok_or_else(|| ParseError::end_of_input(raw_input, "a start `S`"))?;
+        let end = end.ok_or_else(|| ParseError::end_of_input(raw_input, "an end `E`"))?;
+
+        Ok((Grid::new(cells, h, w), start, end))
+    }
+
+    fn solve_first(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output1> {
+        shortest_route(&input.0, input.1, input.2)
+    }
+
+    fn solve_second(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output2> {
+        let starting_points = input.0.filter('a');
+        starting_points
+            .into_iter()
+            .filter_map(|start| shortest_route(&input.0, start, input.2))
+            .min()
+    }
+}
+
+/// A heightmap of `size` rows, at least 5, four times as wide and at least 30
+/// columns. It rises towards the best signal, with the start in the first
+/// column, and a path up to it stays untouched by the bumps elsewhere.
+impl InputGenerator for Problem {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let h = size.max(5);
+        let w = (h * 4).max(30);
+        let start = (0, rng.below(h));
+        let end = (rng.range(25..=w - 1), rng.below(h));
+        let to_end = |x: usize, y: usize| x.abs_diff(end.0) + y.abs_diff(end.1);
+
+        // At most one higher per step towards the end
+        let farthest = to_end(start.0, start.1);
+        let on_path = |x: usize, y: usize| {
+            let rows = start.1.min(end.1)..=start.1.max(end.1);
+            (y == start.1 && x <= end.0) || (x == end.0 && rows.contains(&y))
+        };
+        let rows: Vec<String> = (0..h)
+            .map(|y| {
+                (0..w)
+                    .map(|x| {
+                        let height = 25 - (25 * to_end(x, y) / farthest).min(25);
+                        let height = match on_path(x, y) || !rng.one_in(3) {
+                            true => height,
+                            false => rng.range(height.saturating_sub(3)..=(height + 2).min(25)),
+                        };
+                        match (x, y) {
+                            p if p == start => 'S',
+                            p if p == end => 'E',
+                            _ => (b'a' + height as u8) as char,
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        rows.join("\n")
+    }
+
+    fn real_size(&self) -> usize {
+        41
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_and_end() {
+        let (grid, start, end) = Problem {}.parse_input("abc\nbSd\nEcd").unwrap();
+        assert_eq!((start, end), (GridPoint::new(1, 1), GridPoint::new(0, 2)));
+        assert_eq!(grid.get(&start), Some(&'a'));
+        assert_eq!(grid.get(&end), Some(&'z'));
+        assert_eq!(Problem {}.solve_first(&(grid, start, end), &()), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Problem {}.parse_input("abc\nbSd\nacd").unwrap_err();
+        assert_eq!(error.expected, "an end `E`");
+        let error = Problem {}.parse_input("abc\nbEd\nacd").unwrap_err();
+        assert_eq!(error.expected, "a start `S`");
+
+        let error = Problem {}.parse_input("abc\nbSd\nEc1").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(error.text, "1");
+
+        // Widths count characters, not bytes
+        let error = Problem {}.parse_input("abé\nbSd\nEcd").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.text, "é");
+    }
+}
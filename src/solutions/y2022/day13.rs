@@ -0,0 +1,158 @@
+use core::str;
+use nom::{
+    branch::alt, bytes::complete::tag, character::complete::i64, combinator::map,
+    multi::separated_list0, sequence::delimited, IResult,
+};
+use std::{cmp::Ordering, fmt};
+
+use aoc_core::generate::{InputGenerator, Rng};
+use aoc_core::parse::ParseError;
+use aoc_core::solver::Solver;
+use aoc_core::{register, register_generator};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Packet {
+    Int(i64),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    fn parse(line: &str) -> IResult<&str, Packet> {
+        alt((
+            map(i64, Packet::Int),
+            map(
+                delimited(tag("["), separated_list0(tag(","), Packet::parse), tag("]")),
+                Packet::List,
+            ),
+        ))(line)
+    }
+
+    /// A list nested at most `depth` lists deep
+    fn random(rng: &mut Rng, depth: usize) -> Packet {
+        let items = (0..rng.range(0..=4))
+            .map(|_| match depth > 1 && rng.one_in(3) {
+                true => Packet::random(rng, depth - 1),
+                false => Packet::Int(rng.range(0..=10) as i64),
+            })
+            .collect();
+        Packet::List(items)
+    }
+
+    pub fn new(input: &str, line: &str) -> Result<Self, ParseError> {
+        match Self::parse(line) {
+            Ok(("", packet)) => Ok(packet),
+            Ok((rest, _)) => Err(ParseError::at(input, rest, "end of packet")),
+            Err(_) => Err(ParseError::at(input, line, "a packet")),
+        }
+    }
+}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Int(n) => write!(f, "{}", n),
+            Packet::List(items) => {
+                let items: Vec<String> = items.iter().map(Packet::to_string).collect();
+                write!(f, "[{}]", items.join(","))
+            }
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Int(s), Packet::Int(o)) => s.cmp(o),
+            (Packet::List(_), Packet::Int(_)) => self.cmp(&Packet::List(vec![other.clone()])),
+            (Packet::Int(_), Packet::List(_)) => Packet::List(vec![self.clone()]).cmp(other),
+            (Packet::List(s), Packet::List(o)) => {
+                for (v1, v2) in s.iter().zip(o) {
+                    let result = v1.cmp(v2);
+                    if result != Ordering::Equal {
+                        return result;
+                    }
+                }
+
+                s.len().cmp(&o.len())
+            }
+        }
+    }
+}
+
+pub struct Problem;
+
+register!(2022, 13, Problem);
+register_generator!(2022, 13, Problem);
+
+impl Solver for Problem {
+    type Input<'a> = Vec<(Packet, Packet)>;
+    type Prepared<'a> = ();
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        raw_input
+            .split("\n\n")
+            .map(|pairs| {
+                let mut x = pairs.lines().map(|l| Packet::new(raw_input, l));
+                let mut next = || {
+                    x.next().unwrap_or_else(|| {
+                        Err(ParseError::at(raw_input, &pairs[pairs.len()..], "a packet"))
+                    })
+                };
+
+                Ok((next()?, next()?))
+            })
+            .collect()
+    }
+
+    fn solve_first(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output1> {
+        Some(
+            input
+                .iter()
+                .enumerate()
+                .filter(|(_, (first, second))| first <= second)
+                .map(|(i, _)| i + 1)
+                .sum(),
+        )
+    }
+
+    fn solve_second(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output2> {
+        let mut packets: Vec<_> = input
+            .iter()
+            .flat_map(|x| vec![x.0.clone(), x.1.clone()])
+            .collect();
+
+        let divider_1 = Packet::List(vec![Packet::List(vec![Packet::Int(2)])]);
+        let divider_2 = Packet::List(vec![Packet::List(vec![Packet::Int(6)])]);
+        packets.push(divider_1.clone());
+        packets.push(divider_2.clone());
+
+        packets.sort_unstable();
+
+        let pos1 = packets.binary_search(&divider_1).unwrap() + 1;
+        let pos2 = packets.binary_search(&divider_2).unwrap() + 1;
+
+        Some(pos1 * pos2)
+    }
+}
+
+/// `size` pairs of packets
+impl InputGenerator for Problem {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let pairs: Vec<String> = (0..size.max(1))
+            .map(|_| format!("{}\n{}", Packet::random(rng, 5), Packet::random(rng, 5)))
+            .collect();
+        pairs.join("\n\n")
+    }
+
+    fn real_size(&self) -> usize {
+        150
+    }
+}
@@ -0,0 +1,227 @@
+use aoc_core::{
+    generate::{InputGenerator, Rng},
+    parse::{parse_token, ParseError},
+    register, register_generator,
+    solver::Solver,
+};
+use aoc_grid::{Coord, Grid, GridPoint};
+
+use std::ops::Range;
+
+/// The deepest rock, as the sand piled on part 2's floor spreads one column
+/// further from x 500 each row down and must not go past x 0
+const MAX_DEPTH: usize = 498;
+
+pub struct Problem;
+
+register!(2022, 14, Problem);
+register_generator!(2022, 14, Problem);
+
+impl Solver for Problem {
+    type Input<'a> = Grid<char>;
+    /// The depth of the lowest rock
+    type Prepared<'a> = isize;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let lines: Vec<Vec<(usize, usize)>> = raw_input
+            .lines()
+            .map(|l| {
+                l.split("->")
+                    .map(|coord| {
+                        let coord = coord.trim();
+                        let (x, y) = coord.split_once(',').ok_or_else(|| {
+                            ParseError::at(raw_input, coord, "a point like 498,4")
+                        })?;
+                        let x = parse_token(raw_input, x, "a number")?;
+                        match parse_token(raw_input, y, "a number")? {
+                            depth if depth > MAX_DEPTH => Err(ParseError::at(
+                                raw_input,
+                                y,
+                                format!("a depth of at most {}", MAX_DEPTH),
+                            )),
+                            depth => Ok((x, depth)),
+                        }
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+
+        // Tall enough for part 2's floor and wide enough for the sand on it
+        let points = lines.iter().flatten();
+        let lowest = points.clone().map(|&(_, y)| y).max().unwrap_or(0);
+        let h = lowest + 3;
+        let w = points.map(|&(x, _)| x + 1).max().unwrap_or(0).max(500 + h);
+        let mut grid = Grid::new(vec!['.'; w * h], h, w);
+        for line in lines.into_iter() {
+            for window in line.windows(2) {
+                let (start, target) = (window[0], window[1]);
+                let mut pos = GridPoint::new(start.0 as isize, start.1 as isize);
+                let target = GridPoint::new(target.0 as isize, target.1 as isize);
+                grid.set(&pos, '#');
+
+                while pos.x() != target.x() || pos.y() != target.y() {
+                    pos = pos.add((
+                        (target.x() - pos.x()).signum(),
+                        (target.y() - pos.y()).signum(),
+                    ));
+                    grid.set(&pos, '#');
+                }
+            }
+        }
+
+        Ok(grid)
+    }
+
+    fn prepare<'a>(&self, input: &Self::Input<'a>) -> Self::Prepared<'a> {
+        input
+            .filter('#')
+            .iter()
+            .map(|rock| rock.y())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn solve_first(&self, input: &Self::Input<'_>, &lowest: &isize) -> Option<Self::Output1> {
+        let mut grid = input.clone();
+
+        'outer: loop {
+            // new sand
+            let mut sand = GridPoint::new(500, 0);
+
+            if *grid.get(&sand)? == 'o' {
+                break;
+            }
+
+            loop {
+                if sand.y() + 1 > lowest {
+                    break 'outer;
+                }
+                //down
+                if *grid.get(&sand.add((0, 1)))? == '.' {
+                    sand = sand.add((0, 1));
+                    continue;
+                }
+
+                //left
+                if *grid.get(&sand.add((-1, 1)))? == '.' {
+                    sand = sand.add((-1, 1));
+                    continue;
+                }
+                //right
+                if *grid.get(&sand.add((1, 1)))? == '.' {
+                    sand = sand.add((1, 1));
+                    continue;
+                }
+
+                grid.set(&sand, 'o');
+                break;
+            }
+        }
+
+        Some(grid.filter('o').len())
+    }
+
+    fn solve_second(&self, input: &Self::Input<'_>, &lowest: &isize) -> Option<Self::Output2> {
+        let mut grid = input.clone();
+
+        for x in 0..grid.w {
+            grid.set(&(x, (lowest + 2) as usize), '#');
+        }
+
+        loop {
+            // new sand
+            let mut sand = GridPoint::new(500, 0);
+
+            if *grid.get(&sand)? == 'o' {
+                break;
+            }
+
+            loop {
+                //down
+                if *grid.get(&sand.add((0, 1)))? == '.' {
+                    sand = sand.add((0, 1));
+                    continue;
+                }
+
+                //left
+                if *grid.get(&sand.add((-1, 1)))? == '.' {
+                    sand = sand.add((-1, 1));
+                    continue;
+                }
+                //right
+                if *grid.get(&sand.add((1, 1)))? == '.' {
+                    sand = sand.add((1, 1));
+                    continue;
+                }
+
+                grid.set(&sand, 'o');
+                break;
+            }
+        }
+
+        Some(grid.filter('o').len())
+    }
+}
+
+#[allow(dead_code)]
+fn print_grid(grid: &Grid<char>, xs: Range<usize>, ys: Range<usize>) {
+    for y in ys {
+        for x in xs.clone() {
+            let v = grid.get(&(x, y)).unwrap();
+            print!("{v}");
+        }
+        println!();
+    }
+}
+
+/// `size` rock paths below and around the sand source, shallow enough for the
+/// floor of part 2 and the sand piled on it to fit the grid
+impl InputGenerator for Problem {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let paths: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let (mut x, mut y) = (rng.range(460..=540), rng.range(13..=170));
+                let mut points = vec![format!("{},{}", x, y)];
+                for turn in 0..rng.range(1..=5) {
+                    let length = rng.range(1..=8);
+                    match (turn % 2 == 0, rng.one_in(2)) {
+                        (true, true) => x = (x + length).min(560),
+                        (true, false) => x = x.saturating_sub(length).max(440),
+                        (false, true) => y = (y + length).min(170),
+                        (false, false) => y = y.saturating_sub(length).max(13),
+                    }
+                    points.push(format!("{},{}", x, y));
+                }
+                points.join(" -> ")
+            })
+            .collect();
+        paths.join("\n")
+    }
+
+    fn real_size(&self) -> usize {
+        150
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rock_past_the_source() {
+        let grid = Problem
+            .parse_input("498,4 -> 498,6\n900,30 -> 900,31")
+            .unwrap();
+        assert_eq!(grid.get(&(900, 31)), Some(&'#'));
+        assert_eq!(Problem.prepare(&grid), 31);
+    }
+
+    #[test]
+    fn test_too_deep() {
+        let error = Problem.parse_input("498,4 -> 498,6\n500,499").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.text, "499");
+    }
+}
@@ -0,0 +1,364 @@
+use std::collections::HashSet;
+
+use aoc_core::{
+    generate::{InputGenerator, Rng},
+    parse::{parse_token, ParseError},
+    register, register_generator,
+    solver::{parse_param, Solver},
+};
+use aoc_grid::{Coord, GridPoint};
+
+pub struct Problem {
+    /// Row where beacons can't be in part 1
+    pub row: isize,
+    /// Largest coordinate of the distress beacon in part 2
+    pub at_most: isize,
+}
+
+impl Default for Problem {
+    fn default() -> Self {
+        Self {
+            row: 2000000,
+            at_most: 4000000,
+        }
+    }
+}
+
+/// Part 1 by checking every position of the row within reach of each sensor
+/// one by one, kept to crosscheck the interval based [`Problem`] against
+#[derive(Default)]
+pub struct Naive(Problem);
+
+register!(2022, 15, Problem::default());
+register!(2022, 15, "naive", Naive::default());
+register_generator!(2022, 15, Problem::default());
+
+fn get_gridpoint(input: &str, s: &str) -> Result<GridPoint, ParseError> {
+    let (raw_x, raw_y) = s
+        .split_once(", y=")
+        .ok_or_else(|| ParseError::at(input, s, "a position like x=2, y=18"))?;
+    let raw_x = raw_x
+        .split_once("x=")
+        .ok_or_else(|| ParseError::at(input, raw_x, "`x=`"))?
+        .1;
+
+    Ok(GridPoint::new(
+        parse_token(input, raw_x, "a number")?,
+        parse_token(input, raw_y, "a number")?,
+    ))
+}
+
+fn impossibles_at_row(
+    sensors_with_distances: &[(GridPoint, usize)],
+    row: isize,
+) -> HashSet<GridPoint> {
+    let mut impossible_positions: HashSet<GridPoint> = HashSet::new();
+
+    for (start, distance) in sensors_with_distances {
+        let distance_x = *distance as isize;
+        for x in start.x() - distance_x..=start.x() + distance_x {
+            let point = GridPoint::new(x, row);
+            if start.distance(&point) <= *distance {
+                impossible_positions.insert(point);
+            }
+        }
+    }
+
+    impossible_positions
+}
+
+/// The ranges of the row the sensors cover, merged and in order
+fn covered_at_row(
+    sensors_with_distances: &[(GridPoint, usize)],
+    row: isize,
+) -> Vec<(isize, isize)> {
+    let mut ranges: Vec<(isize, isize)> = sensors_with_distances
+        .iter()
+        .filter_map(|&(sensor, distance)| {
+            let reach = distance as isize - (sensor.y() - row).abs();
+            (reach >= 0).then_some((sensor.x() - reach, sensor.x() + reach))
+        })
+        .collect();
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(isize, isize)> = Vec::new();
+    for (from, to) in ranges {
+        match merged.last_mut() {
+            Some((_, end)) if from <= *end + 1 => *end = (*end).max(to),
+            _ => merged.push((from, to)),
+        }
+    }
+    merged
+}
+
+fn blind_spot(sensors_with_distances: &[(GridPoint, usize)], point: &GridPoint) -> bool {
+    sensors_with_distances
+        .iter()
+        .all(|&(beacon, distance)| beacon.distance(point) > distance)
+}
+
+impl Solver for Problem {
+    type Input<'a> = Vec<(GridPoint, GridPoint)>;
+    /// Every sensor with the distance to its beacon, which no other beacon is
+    /// closer than
+    type Prepared<'a> = Vec<(GridPoint, usize)>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        raw_input
+            .lines()
+            .map(|l| {
+                let (raw_sensor, raw_beacon) = l
+                    .split_once(": ")
+                    .ok_or_else(|| ParseError::at(raw_input, l, "a sensor and a beacon"))?;
+
+                Ok((
+                    get_gridpoint(raw_input, raw_sensor)?,
+                    get_gridpoint(raw_input, raw_beacon)?,
+                ))
+            })
+            .collect()
+    }
+
+    fn prepare<'a>(&self, input: &Self::Input<'a>) -> Self::Prepared<'a> {
+        input
+            .iter()
+            .map(|(sensor, beacon)| (*sensor, sensor.distance(beacon)))
+            .collect()
+    }
+
+    fn solve_first(
+        &self,
+        input: &Self::Input<'_>,
+        sensors_with_distances: &Self::Prepared<'_>,
+    ) -> Option<Self::Output1> {
+        let covered = covered_at_row(sensors_with_distances, self.row);
+        let beacons: HashSet<isize> = input
+            .iter()
+            .filter(|(_, beacon)| beacon.y() == self.row)
+            .map(|(_, beacon)| beacon.x())
+            .filter(|x| covered.iter().any(|(from, to)| (from..=to).contains(&x)))
+            .collect();
+
+        let positions: usize = covered
+            .iter()
+            .map(|(from, to)| (to - from + 1) as usize)
+            .sum();
+        Some(positions - beacons.len())
+    }
+
+    fn solve_second(
+        &self,
+        _input: &Self::Input<'_>,
+        sensors_with_distances: &Self::Prepared<'_>,
+    ) -> Option<Self::Output2> {
+        sensors_with_distances
+            .iter()
+            .filter_map(|&(sensor, distance)| {
+                let (x, y) = (sensor.x(), sensor.y());
+                let mut d_y = 0;
+                for n_x in x - distance as isize - 1..x.min(self.at_most + 1) {
+                    if n_x < 0 {
+                        d_y += 1;
+                        continue;
+                    }
+
+                    for n_y in [y + d_y, y - d_y] {
+                        let point = GridPoint::new(n_x, n_y);
+                        if (0..=self.at_most).contains(&n_y)
+                            && blind_spot(sensors_with_distances, &point)
+                        {
+                            return Some(point);
+                        }
+                    }
+
+                    d_y += 1;
+                }
+                None
+            })
+            .map(|p| (p.x() * 4000000 + p.y()) as usize)
+            .next()
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "row" => self.row = parse_param(name, value)?,
+            "at_most" => self.at_most = parse_param(name, value)?,
+            _ => return Err(format!("unknown parameter {}", name)),
+        }
+        Ok(())
+    }
+}
+
+/// `size` sensors, at least 4, for the row and area of the puzzle. Four of them
+/// leave a single position of the area uncovered, the distress beacon, which
+/// the others don't reach either.
+impl InputGenerator for Problem {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let area = |rng: &mut Rng| rng.below(self.at_most as usize + 1) as isize;
+        let distress = GridPoint::new(area(rng), area(rng));
+
+        // A sensor covers a square of x + y and x - y, the four of them cover
+        // every position above, below, left and right of the distress beacon
+        // in those coordinates. Their ranges are odd so their centers are
+        // positions.
+        let (u, v) = (distress.x() + distress.y(), distress.x() - distress.y());
+        let wide = (self.at_most + v.abs()) | 1;
+        let below = ((self.at_most + v) / 2) | 1;
+        let above = ((self.at_most - v) / 2) | 1;
+        let mut sensors: Vec<(GridPoint, usize)> = [
+            (u - 1 - wide, v, wide),
+            (u + 1 + wide, v, wide),
+            (u, v - 1 - below, below),
+            (u, v + 1 + above, above),
+        ]
+        .into_iter()
+        .map(|(u, v, range)| (GridPoint::new((u + v) / 2, (u - v) / 2), range as usize))
+        .collect();
+
+        while sensors.len() < size {
+            let sensor = GridPoint::new(area(rng), area(rng));
+            let to_distress = sensor.distance(&distress);
+            if to_distress > 0 {
+                let range = rng.below(to_distress.min(self.at_most as usize / 4));
+                sensors.push((sensor, range));
+            }
+        }
+
+        let mut lines: Vec<String> = sensors
+            .into_iter()
+            .map(|(sensor, range)| {
+                let dx = rng.range(0..=range) as isize;
+                let dy = range as isize - dx;
+                let beacon = GridPoint::new(
+                    sensor.x() + *rng.choose(&[dx, -dx]),
+                    sensor.y() + *rng.choose(&[dy, -dy]),
+                );
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                    sensor.x(),
+                    sensor.y(),
+                    beacon.x(),
+                    beacon.y()
+                )
+            })
+            .collect();
+        rng.shuffle(&mut lines);
+        lines.join("\n")
+    }
+
+    fn real_size(&self) -> usize {
+        30
+    }
+}
+
+impl Solver for Naive {
+    type Input<'a> = <Problem as Solver>::Input<'a>;
+    type Prepared<'a> = <Problem as Solver>::Prepared<'a>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        self.0.parse_input(raw_input)
+    }
+
+    fn prepare<'a>(&self, input: &Self::Input<'a>) -> Self::Prepared<'a> {
+        self.0.prepare(input)
+    }
+
+    fn solve_first(
+        &self,
+        input: &Self::Input<'_>,
+        sensors_with_distances: &Self::Prepared<'_>,
+    ) -> Option<Self::Output1> {
+        let mut impossible_positions = impossibles_at_row(sensors_with_distances, self.0.row);
+
+        for (_, beacon) in input.iter() {
+            impossible_positions.remove(beacon);
+        }
+
+        Some(impossible_positions.len())
+    }
+
+    /// Only part 1 has a naive version
+    fn solve_second(
+        &self,
+        _input: &Self::Input<'_>,
+        _: &Self::Prepared<'_>,
+    ) -> Option<Self::Output2> {
+        None
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        self.0.set_param(name, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_params() {
+        let mut problem = Problem::default();
+        problem.set_param("row", "10").unwrap();
+        assert_eq!(problem.row, 10);
+        assert!(problem.set_param("at_most", "x").is_err());
+        assert!(problem.set_param("rounds", "10").is_err());
+    }
+
+    #[test]
+    fn test_covered_at_row() {
+        let sensors = [
+            (GridPoint::new(0, 0), 2),
+            (GridPoint::new(3, 1), 1),
+            (GridPoint::new(10, 0), 1),
+        ];
+        assert_eq!(covered_at_row(&sensors, 1), vec![(-1, 4), (10, 10)]);
+        assert_eq!(covered_at_row(&sensors, 5), vec![]);
+    }
+
+    #[test]
+    fn test_naive_far_sensor() {
+        let problem = Problem {
+            row: 10,
+            at_most: 20,
+        };
+        let naive = Naive(Problem {
+            row: 10,
+            at_most: 20,
+        });
+        let input = problem
+            .parse_input("Sensor at x=7000000, y=10: closest beacon is at x=7000003, y=11")
+            .unwrap();
+        let sensors = problem.prepare(&input);
+        assert_eq!(problem.solve_first(&input, &sensors), Some(9));
+        assert_eq!(naive.solve_first(&input, &sensors), Some(9));
+    }
+
+    #[test]
+    fn test_second_inside_area() {
+        // Sensors far outside the area, from the generator, must not make part
+        // 2 answer with a position outside it
+        let problem = Problem {
+            row: 10,
+            at_most: 20,
+        };
+        for seed in 0..100 {
+            let raw_input = problem.generate(&mut Rng::new(seed), 30);
+            let input = problem.parse_input(&raw_input).unwrap();
+            let sensors = problem.prepare(&input);
+            let expected = (0..=20)
+                .flat_map(|x| (0..=20).map(move |y| GridPoint::new(x, y)))
+                .find(|point| blind_spot(&sensors, point))
+                .map(|p| (p.x() * 4000000 + p.y()) as usize);
+            assert_eq!(
+                problem.solve_second(&input, &sensors),
+                expected,
+                "seed {}",
+                seed
+            );
+        }
+    }
+}
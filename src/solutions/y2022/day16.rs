@@ -0,0 +1,316 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, VecDeque};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use aoc_core::generate::{InputGenerator, Rng};
+use aoc_core::parse::{parse_token, ParseError};
+use aoc_core::solver::{parse_param, Solver};
+use aoc_core::{register, register_generator};
+
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct Valve<'a> {
+    name: &'a str,
+    flow_rate: usize,
+    connections: Vec<&'a str>,
+}
+
+/// The valves worth opening, each one a bit of a `u64` mask in the order of
+/// `flow_rates`, and how far they are
+#[derive(Debug, Default)]
+pub struct Network<'a> {
+    /// Flow rates of the valves worth opening
+    flow_rates: Vec<usize>,
+    /// Names of the valves worth opening, in the same order
+    flowing: Vec<&'a str>,
+    /// Minutes to walk from each valve to each valve worth opening, if there
+    /// is a way
+    distances: HashMap<&'a str, Vec<Option<usize>>>,
+}
+
+lazy_static! {
+    static ref RE: Regex = Regex::new(r"([A-Z]{2})").unwrap();
+}
+
+pub struct Problem {
+    /// Minutes until the volcano erupts in part 1
+    pub minutes: usize,
+    /// Minutes left after teaching the elephant in part 2
+    pub elephant_minutes: usize,
+    /// Valve both you and the elephant start at
+    pub start: String,
+}
+
+impl Default for Problem {
+    fn default() -> Self {
+        Self {
+            minutes: 30,
+            elephant_minutes: 26,
+            start: String::from("AA"),
+        }
+    }
+}
+
+register!(2022, 16, Problem::default());
+register_generator!(2022, 16, Problem::default());
+
+impl Solver for Problem {
+    type Input<'a> = HashMap<&'a str, Valve<'a>>;
+    type Prepared<'a> = Network<'a>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let valves: Self::Input<'a> = raw_input
+            .lines()
+            .map(|l| {
+                let rest = l
+                    .strip_prefix("Valve ")
+                    .ok_or_else(|| ParseError::at(raw_input, l, "`Valve `"))?;
+                let (name, rest) = rest
+                    .split_once(" has flow rate=")
+                    .ok_or_else(|| ParseError::at(raw_input, rest, "a valve name and flow rate"))?;
+                let (flow_rate, tunnels) = rest
+                    .split_once(';')
+                    .ok_or_else(|| ParseError::at(raw_input, rest, "a flow rate and tunnels"))?;
+                let flow_rate = parse_token(raw_input, flow_rate, "a flow rate")?;
+                let connections = RE
+                    .captures_iter(tunnels)
+                    .map(|x| x.get(0).unwrap().as_str())
+                    .collect();
+
+                Ok((
+                    name,
+                    Valve {
+                        name,
+                        flow_rate,
+                        connections,
+                    },
+                ))
+            })
+            .collect::<Result<_, _>>()?;
+
+        // A tunnel to a valve without a line of its own would lead nowhere
+        for valve in valves.values() {
+            if let Some(unknown) = valve
+                .connections
+                .iter()
+                .find(|name| !valves.contains_key(*name))
+            {
+                return Err(ParseError::at(raw_input, unknown, "a listed valve"));
+            }
+        }
+
+        Ok(valves)
+    }
+
+    fn prepare<'a>(&self, input: &Self::Input<'a>) -> Self::Prepared<'a> {
+        let mut flowing: Vec<&Valve> = input.values().filter(|v| v.flow_rate > 0).collect();
+        flowing.sort_unstable_by_key(|v| v.name);
+        let distances = input
+            .keys()
+            .map(|&from| {
+                let to_all = distances_from(input, from);
+                let to_flowing = flowing
+                    .iter()
+                    .map(|v| to_all.get(v.name).copied())
+                    .collect();
+                (from, to_flowing)
+            })
+            .collect();
+
+        Network {
+            flow_rates: flowing.iter().map(|v| v.flow_rate).collect(),
+            flowing: flowing.iter().map(|v| v.name).collect(),
+            distances,
+        }
+    }
+
+    fn solve_first(&self, _: &Self::Input<'_>, network: &Network<'_>) -> Option<Self::Output1> {
+        let pressures = best_pressures(network, &self.start, self.minutes)?;
+        pressures.into_values().max()
+    }
+
+    fn solve_second(&self, _: &Self::Input<'_>, network: &Network<'_>) -> Option<Self::Output2> {
+        let pressures = best_pressures(network, &self.start, self.elephant_minutes)?;
+
+        // You and the elephant open disjoint sets of valves. Going from the
+        // best set down, the first disjoint partner is the best for that set.
+        let mut pressures: Vec<(u64, usize)> = pressures.into_iter().collect();
+        pressures.sort_unstable_by_key(|&(_, pressure)| Reverse(pressure));
+        let mut max = 0;
+        for (i, &(mask, pressure)) in pressures.iter().enumerate() {
+            if pressure * 2 <= max {
+                break;
+            }
+            if let Some(&(_, other)) = pressures[i..]
+                .iter()
+                .take_while(|&&(_, other)| pressure + other > max)
+                .find(|&&(other_mask, _)| mask & other_mask == 0)
+            {
+                max = pressure + other;
+            }
+        }
+
+        Some(max)
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "minutes" => self.minutes = parse_param(name, value)?,
+            "elephant_minutes" => self.elephant_minutes = parse_param(name, value)?,
+            "start" => self.start = value.to_string(),
+            _ => return Err(format!("unknown parameter {}", name)),
+        }
+        Ok(())
+    }
+}
+
+/// Minutes to walk from `from` to every valve it leads to, by breadth-first
+/// search
+fn distances_from<'a>(
+    valves: &HashMap<&'a str, Valve<'a>>,
+    from: &'a str,
+) -> HashMap<&'a str, usize> {
+    let mut distances = HashMap::from([(from, 0)]);
+    let mut queue = VecDeque::from([from]);
+    while let Some(valve) = queue.pop_front() {
+        let distance = distances[valve];
+        for &next in valves[valve].connections.iter() {
+            if !distances.contains_key(next) {
+                distances.insert(next, distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// The most pressure released in `minutes` from `start` by opening each set
+/// of valves, keyed by its mask. None if there are too many valves worth
+/// opening for a mask or there is no valve `start`.
+fn best_pressures(
+    network: &Network<'_>,
+    start: &str,
+    minutes: usize,
+) -> Option<HashMap<u64, usize>> {
+    if network.flowing.len() > u64::BITS as usize {
+        return None;
+    }
+    let from = network.distances.get(start)?;
+    let mut best = HashMap::new();
+    open_valves(network, from, minutes, 0, 0, &mut best);
+    Some(best)
+}
+
+/// Records the pressure released with the valves of `opened`, then tries
+/// walking to and opening each other valve in the `minutes` left
+fn open_valves(
+    network: &Network<'_>,
+    from: &[Option<usize>],
+    minutes: usize,
+    opened: u64,
+    released: usize,
+    best: &mut HashMap<u64, usize>,
+) {
+    let entry = best.entry(opened).or_insert(0);
+    *entry = (*entry).max(released);
+
+    for (valve, distance) in from.iter().enumerate() {
+        let bit = 1 << valve;
+        let Some(distance) = distance else {
+            continue;
+        };
+        if opened & bit != 0 || distance + 1 >= minutes {
+            continue;
+        }
+        let minutes = minutes - distance - 1;
+        open_valves(
+            network,
+            &network.distances[network.flowing[valve]],
+            minutes,
+            opened | bit,
+            released + minutes * network.flow_rates[valve],
+            best,
+        );
+    }
+}
+
+/// `size` valves, at least 2 and at most one per two-letter name, joined by a
+/// tree of tunnels from the start valve. A quarter of them have a flow rate,
+/// up to the 64 a mask can hold.
+impl InputGenerator for Problem {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut names: Vec<String> = (b'A'..=b'Z')
+            .flat_map(|a| (b'A'..=b'Z').map(move |b| String::from_utf8(vec![a, b]).unwrap()))
+            .filter(|name| *name != self.start)
+            .collect();
+        rng.shuffle(&mut names);
+        names.insert(0, self.start.clone());
+        names.truncate(size.clamp(2, names.len()));
+
+        let mut tunnels = vec![Vec::new(); names.len()];
+        for valve in 1..names.len() {
+            // Mostly corridors, as in real inputs, which keeps the valves
+            // worth opening a few minutes apart
+            let other = match rng.one_in(4) {
+                true => rng.below(valve),
+                false => valve - 1,
+            };
+            tunnels[valve].push(other);
+            tunnels[other].push(valve);
+        }
+        let mut flowing: Vec<usize> = (1..names.len()).collect();
+        rng.shuffle(&mut flowing);
+        flowing.truncate((names.len() / 4).min(u64::BITS as usize));
+
+        let mut valves: Vec<String> = tunnels
+            .iter_mut()
+            .enumerate()
+            .map(|(valve, tunnels)| {
+                let flow_rate = match flowing.contains(&valve) {
+                    true => rng.range(3..=25),
+                    false => 0,
+                };
+                rng.shuffle(tunnels);
+                let leads_to: Vec<&str> = tunnels.iter().map(|&t| names[t].as_str()).collect();
+                let tunnels = match leads_to.len() {
+                    1 => format!("tunnel leads to valve {}", leads_to[0]),
+                    _ => format!("tunnels lead to valves {}", leads_to.join(", ")),
+                };
+                format!(
+                    "Valve {} has flow rate={}; {}",
+                    names[valve], flow_rate, tunnels
+                )
+            })
+            .collect();
+        rng.shuffle(&mut valves);
+        valves.join("\n")
+    }
+
+    fn real_size(&self) -> usize {
+        60
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Valve AA has flow rate=0; tunnels lead to valves BB\nValve BB has flow rate=x3; tunnel leads to valve AA";
+        let error = Problem::default().parse_input(input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 24));
+        assert_eq!(error.text, "x3");
+    }
+
+    #[test]
+    fn test_unknown_tunnel() {
+        let input = "Valve AA has flow rate=0; tunnels lead to valves BB, CC\nValve BB has flow rate=3; tunnel leads to valve AA";
+        let error = Problem::default().parse_input(input).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 54));
+        assert_eq!(error.text, "CC");
+    }
+}
@@ -0,0 +1,134 @@
+use aoc_core::generate::{InputGenerator, Rng};
+use aoc_core::parse::ParseError;
+use aoc_core::solver::Solver;
+use aoc_core::{register, register_generator};
+
+pub struct Problem;
+
+register!(2022, 2, Problem);
+register_generator!(2022, 2, Problem);
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Hand {
+    Rock = 1,
+    Paper = 2,
+    Scissor = 3,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum Winner {
+    Left = 0,
+    Draw = 3,
+    Right = 6,
+}
+
+impl Winner {
+    pub fn value(&self) -> u128 {
+        *self as u128
+    }
+}
+
+impl Hand {
+    fn value(&self) -> u128 {
+        *self as u128
+    }
+
+    fn beats(&self) -> Self {
+        match *self {
+            Hand::Rock => Hand::Scissor,
+            Hand::Paper => Hand::Rock,
+            Hand::Scissor => Hand::Paper,
+        }
+    }
+
+    fn get_winner(left: Self, right: Self) -> Winner {
+        if left == right {
+            Winner::Draw
+        } else if left.beats() == right {
+            Winner::Left
+        } else {
+            Winner::Right
+        }
+    }
+}
+
+impl From<Hand> for Winner {
+    fn from(val: Hand) -> Self {
+        match val {
+            Hand::Rock => Winner::Left,
+            Hand::Paper => Winner::Draw,
+            Hand::Scissor => Winner::Right,
+        }
+    }
+}
+
+impl Solver for Problem {
+    type Input<'a> = Vec<(Hand, Hand)>;
+    type Prepared<'a> = ();
+    type Output1 = u128;
+    type Output2 = u128;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let hand = |c| match c {
+            "A" | "X" => Ok(Hand::Rock),
+            "B" | "Y" => Ok(Hand::Paper),
+            "C" | "Z" => Ok(Hand::Scissor),
+            c => Err(ParseError::at(raw_input, c, "one of A, B, C, X, Y or Z")),
+        };
+
+        raw_input
+            .lines()
+            .map(|l| {
+                let (left, right) = l
+                    .split_once(' ')
+                    .ok_or_else(|| ParseError::at(raw_input, l, "two hands"))?;
+                Ok((hand(left)?, hand(right)?))
+            })
+            .collect()
+    }
+
+    fn solve_first(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output1> {
+        Some(
+            input
+                .iter()
+                .map(|hands| {
+                    let winner = Hand::get_winner(hands.0, hands.1);
+                    winner.value() + hands.1.value()
+                })
+                .sum(),
+        )
+    }
+
+    fn solve_second(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output2> {
+        Some(
+            input
+                .iter()
+                .map(|hands| match hands.1.into() {
+                    Winner::Left => hands.0.beats().value() + Winner::Left.value(),
+                    Winner::Draw => hands.0.value() + Winner::Draw.value(),
+                    Winner::Right => hands.0.beats().beats().value() + Winner::Right.value(),
+                })
+                .sum(),
+        )
+    }
+}
+
+/// `size` rounds of the strategy guide
+impl InputGenerator for Problem {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let rounds: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}",
+                    rng.choose(&["A", "B", "C"]),
+                    rng.choose(&["X", "Y", "Z"])
+                )
+            })
+            .collect();
+        rounds.join("\n")
+    }
+
+    fn real_size(&self) -> usize {
+        2500
+    }
+}
@@ -0,0 +1,123 @@
+use itertools::Itertools;
+
+use aoc_core::generate::{InputGenerator, Rng};
+use aoc_core::parse::ParseError;
+use aoc_core::solver::Solver;
+use aoc_core::{register, register_generator};
+use std::collections::HashSet;
+
+pub struct Problem;
+
+register!(2022, 3, Problem);
+register_generator!(2022, 3, Problem);
+
+impl Solver for Problem {
+    type Input<'a> = Vec<Vec<u128>>;
+    type Prepared<'a> = ();
+    type Output1 = u128;
+    type Output2 = u128;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        raw_input
+            .lines()
+            .map(|line| {
+                line.char_indices()
+                    .map(|(i, x)| match x {
+                        'A'..='Z' => Ok(x as u128 - 38),
+                        'a'..='z' => Ok(x as u128 - 96),
+                        _ => Err(ParseError::at(
+                            raw_input,
+                            &line[i..i + x.len_utf8()],
+                            "an item letter",
+                        )),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn solve_first(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output1> {
+        Some(
+            input
+                .iter()
+                .map(|rucksack| {
+                    let mut chunks = rucksack.chunks(rucksack.len() / 2);
+                    let first_half_chars: HashSet<&u128> = chunks.next().unwrap().iter().collect();
+
+                    chunks
+                        .next()
+                        .unwrap()
+                        .iter()
+                        .find(|x| first_half_chars.contains(x))
+                        .unwrap()
+                })
+                .sum(),
+        )
+    }
+
+    fn solve_second(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output2> {
+        Some(
+            input
+                .iter()
+                .chunks(3)
+                .into_iter()
+                .map(|chunk| {
+                    let reduction = chunk
+                        .map(|x| x.iter().collect::<HashSet<_>>())
+                        .reduce(|accum, item| {
+                            accum.intersection(&item).copied().collect::<HashSet<_>>()
+                        })
+                        .unwrap();
+
+                    *reduction.iter().next().unwrap()
+                })
+                .sum(),
+        )
+    }
+}
+
+/// `size` rucksacks, rounded up to whole groups of three. Each group only
+/// shares its badge and the halves of each rucksack only share one item.
+impl InputGenerator for Problem {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+        let mut rucksacks = Vec::new();
+        for _ in 0..size.max(1).div_ceil(3) {
+            let badge = *rng.choose(&items);
+            let mut others: Vec<char> = items.iter().copied().filter(|&i| i != badge).collect();
+            rng.shuffle(&mut others);
+            // Every rucksack of the group gets its own items besides the badge
+            for own in others.chunks(others.len() / 3) {
+                rucksacks.push(rucksack(rng, own, badge));
+            }
+        }
+        rucksacks.join("\n")
+    }
+
+    fn real_size(&self) -> usize {
+        300
+    }
+}
+
+/// A rucksack of the `own` items and the `badge`, with one item in both halves
+fn rucksack(rng: &mut Rng, own: &[char], badge: char) -> String {
+    let shared = *rng.choose(&[own, &[badge]].concat());
+    let mut rest: Vec<char> = own.iter().copied().filter(|&i| i != shared).collect();
+    rng.shuffle(&mut rest);
+    let (left, right) = rest.split_at(rest.len() / 2);
+
+    let len = rng.range(4..=16);
+    let badge_left = rng.one_in(2);
+    let mut half = |items: &[char], has_badge: bool| {
+        let mut half = vec![shared];
+        if has_badge && badge != shared {
+            half.push(badge);
+        }
+        while half.len() < len {
+            half.push(*rng.choose(items));
+        }
+        rng.shuffle(&mut half);
+        half.into_iter().collect::<String>()
+    };
+    half(left, badge_left) + &half(right, !badge_left)
+}
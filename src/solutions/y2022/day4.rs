@@ -0,0 +1,75 @@
+use aoc_core::generate::{InputGenerator, Rng};
+use aoc_core::parse::{parse_token, ParseError};
+use aoc_core::solver::Solver;
+use aoc_core::{register, register_generator};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub struct Problem;
+
+register!(2022, 4, Problem);
+register_generator!(2022, 4, Problem);
+
+lazy_static! {
+    static ref ASSIGNMENTS_RE: Regex = Regex::new(r"(\d+)-(\d+),(\d+)-(\d+)").unwrap();
+}
+
+impl Solver for Problem {
+    type Input<'a> = Vec<((u128, u128), (u128, u128))>;
+    type Prepared<'a> = ();
+    type Output1 = u128;
+    type Output2 = u128;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        raw_input
+            .lines()
+            .map(|line| {
+                let captures = ASSIGNMENTS_RE
+                    .captures(line)
+                    .ok_or_else(|| ParseError::at(raw_input, line, "two ranges like 2-4,6-8"))?;
+                let section =
+                    |i| parse_token(raw_input, captures.get(i).unwrap().as_str(), "a section");
+
+                Ok(((section(1)?, section(2)?), (section(3)?, section(4)?)))
+            })
+            .collect()
+    }
+
+    fn solve_first(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output1> {
+        Some(
+            input
+                .iter()
+                .filter(|(one, other)| {
+                    (one.0 <= other.0 && one.1 >= other.1) || (other.0 <= one.0 && other.1 >= one.1)
+                })
+                .count() as u128,
+        )
+    }
+
+    fn solve_second(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output2> {
+        Some(
+            input
+                .iter()
+                .filter(|(one, other)| one.0 <= other.1 && other.0 <= one.1)
+                .count() as u128,
+        )
+    }
+}
+
+/// `size` pairs of section assignments
+impl InputGenerator for Problem {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let assignment = |rng: &mut Rng| {
+            let from = rng.range(1..=99);
+            format!("{}-{}", from, rng.range(from..=99))
+        };
+        let pairs: Vec<String> = (0..size.max(1))
+            .map(|_| format!("{},{}", assignment(rng), assignment(rng)))
+            .collect();
+        pairs.join("\n")
+    }
+
+    fn real_size(&self) -> usize {
+        1000
+    }
+}
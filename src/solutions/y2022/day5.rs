@@ -0,0 +1,148 @@
+use aoc_core::generate::{InputGenerator, Rng};
+use aoc_core::parse::{parse_token, ParseError};
+use aoc_core::solver::Solver;
+use aoc_core::{register, register_generator};
+use scan_fmt::scan_fmt;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+pub struct Problem;
+
+register!(2022, 5, Problem);
+register_generator!(2022, 5, Problem);
+
+impl Solver for Problem {
+    type Input<'a> = (Vec<Vec<char>>, Vec<Instruction>);
+    type Prepared<'a> = ();
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let (raw_boxes, raw_instructions) = raw_input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::end_of_input(raw_input, "a blank line after the stacks"))?;
+        let mut boxes_lines: Vec<&str> = raw_boxes.lines().collect();
+
+        let numbers = boxes_lines
+            .pop()
+            .ok_or_else(|| ParseError::at(raw_input, raw_boxes, "the stack numbers"))?;
+        let stacks: usize = parse_token(
+            raw_input,
+            numbers.split_ascii_whitespace().last().unwrap_or(numbers),
+            "the number of stacks",
+        )?;
+
+        let mut boxes = vec![Vec::<char>::new(); stacks];
+        for line in boxes_lines {
+            for (pos, chars) in line.chars().collect::<Vec<char>>().chunks(4).enumerate() {
+                match chars.get(1) {
+                    Some(c) if c.is_alphabetic() && pos < stacks => boxes[pos].push(*c),
+                    Some(' ') | None => {}
+                    _ => {
+                        let start = line.char_indices().nth(pos * 4).unwrap().0;
+                        return Err(ParseError::at(raw_input, &line[start..], "a crate"));
+                    }
+                }
+            }
+        }
+
+        let instructions = raw_instructions
+            .lines()
+            .map(|l| {
+                let (count, from, to) = scan_fmt!(l, "move {} from {} to {}", usize, usize, usize)
+                    .map_err(|_| ParseError::at(raw_input, l, "move N from N to N"))?;
+                if from == 0 || from > stacks || to == 0 || to > stacks {
+                    return Err(ParseError::at(
+                        raw_input,
+                        l,
+                        "a move between existing stacks",
+                    ));
+                }
+                Ok(Instruction { count, from, to })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok((boxes, instructions))
+    }
+
+    fn solve_first(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output1> {
+        let mut boxes = input.0.clone();
+        for instruction in input.1.iter() {
+            let mut from = boxes[instruction.from - 1].clone();
+            let to = boxes[instruction.to - 1].clone();
+            let mut x: Vec<char> = from.drain(..instruction.count).collect();
+            x.reverse();
+            x.extend(to);
+
+            boxes[instruction.from - 1] = from;
+            boxes[instruction.to - 1] = x;
+        }
+
+        Some(boxes.into_iter().map(|b| b[0]).collect())
+    }
+
+    fn solve_second(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output2> {
+        let mut boxes = input.0.clone();
+        for instruction in input.1.iter() {
+            let mut from = boxes[instruction.from - 1].clone();
+            let to = boxes[instruction.to - 1].clone();
+            let mut x: Vec<char> = from.drain(..instruction.count).collect();
+            x.extend(to);
+
+            boxes[instruction.from - 1] = from;
+            boxes[instruction.to - 1] = x;
+        }
+
+        Some(boxes.into_iter().map(|b| b[0]).collect())
+    }
+}
+
+/// Nine stacks of crates and `size` moves, which never take the last crate of
+/// a stack
+impl InputGenerator for Problem {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut heights: Vec<usize> = (0..9).map(|_| rng.range(1..=8)).collect();
+        // Something to move from the start
+        heights[0] = heights[0].max(2);
+
+        let tallest = *heights.iter().max().unwrap();
+        let mut drawing: Vec<String> = (0..tallest)
+            .rev()
+            .map(|level| {
+                let crates: Vec<String> = heights
+                    .iter()
+                    .map(|&height| match level < height {
+                        true => format!("[{}]", (b'A' + rng.below(26) as u8) as char),
+                        false => String::from("   "),
+                    })
+                    .collect();
+                crates.join(" ")
+            })
+            .collect();
+        let numbers: Vec<String> = (1..=heights.len()).map(|n| format!(" {} ", n)).collect();
+        drawing.push(numbers.join(" "));
+
+        let mut moves = Vec::new();
+        for _ in 0..size.max(1) {
+            let from: Vec<usize> = (0..heights.len()).filter(|&s| heights[s] > 1).collect();
+            let from = *rng.choose(&from);
+            let mut to = rng.below(heights.len() - 1);
+            if to >= from {
+                to += 1;
+            }
+            let count = rng.range(1..=(heights[from] - 1).min(10));
+            heights[from] -= count;
+            heights[to] += count;
+            moves.push(format!("move {} from {} to {}", count, from + 1, to + 1));
+        }
+        format!("{}\n\n{}", drawing.join("\n"), moves.join("\n"))
+    }
+
+    fn real_size(&self) -> usize {
+        500
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::HashSet;
+
+use aoc_core::generate::{InputGenerator, Rng};
+use aoc_core::parse::ParseError;
+use aoc_core::solver::Solver;
+use aoc_core::{register, register_generator};
+
+pub struct Problem;
+
+register!(2022, 6, Problem);
+register_generator!(2022, 6, Problem);
+
+impl Solver for Problem {
+    type Input<'a> = Vec<char>;
+    type Prepared<'a> = ();
+    type Output1 = u128;
+    type Output2 = u128;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Ok(raw_input.chars().collect())
+    }
+
+    fn solve_first(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output1> {
+        Some(
+            input
+                .as_slice()
+                .windows(4)
+                .enumerate()
+                .find(|(_i, x)| x.iter().collect::<HashSet<_>>().len() == x.len())
+                .unwrap()
+                .0 as u128
+                + 4,
+        )
+    }
+
+    fn solve_second(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output2> {
+        Some(
+            input
+                .as_slice()
+                .windows(14)
+                .enumerate()
+                .find(|(_i, x)| x.iter().collect::<HashSet<_>>().len() == x.len())
+                .unwrap()
+                .0 as u128
+                + 14,
+        )
+    }
+}
+
+/// A datastream of `size` characters, at least 27, with both markers
+/// somewhere in it
+impl InputGenerator for Problem {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let size = size.max(27);
+        let letter = |rng: &mut Rng| (b'a' + rng.below(26) as u8) as char;
+        let message = rng.range(size / 2..=size - 14);
+        // Far enough for 13 characters to repeat after the first marker
+        let packet = rng.range(9..=message - 4);
+
+        let mut stream: Vec<char> = (0..3).map(|_| letter(rng)).collect();
+        extend(rng, &mut stream, packet, 4);
+        extend(rng, &mut stream, message, 14);
+        while stream.len() < size {
+            stream.push(letter(rng));
+        }
+        stream.into_iter().collect()
+    }
+
+    fn real_size(&self) -> usize {
+        4096
+    }
+}
+
+/// Repeats one of the last `marker - 1` characters of `stream` until it is
+/// `len` long, so no marker of that length can end there, then adds one
+fn extend(rng: &mut Rng, stream: &mut Vec<char>, len: usize, marker: usize) {
+    while stream.len() < len {
+        let last = stream[stream.len() - (marker - 1)..].to_vec();
+        stream.push(*rng.choose(&last));
+    }
+    let mut letters: Vec<char> = ('a'..='z').collect();
+    rng.shuffle(&mut letters);
+    stream.extend(&letters[..marker]);
+}
@@ -0,0 +1,197 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::generate::{InputGenerator, Rng};
+use aoc_core::parse::{parse_token, ParseError};
+use aoc_core::solver::Solver;
+use aoc_core::{register, register_generator};
+
+/// The names of the directories leading to one, from the root
+type Path<'a> = Vec<&'a str>;
+/// What `ls` listed in every directory, with a size of 0 for directories
+type FS<'a> = HashMap<Path<'a>, HashSet<(usize, &'a str)>>;
+
+const ROOT: &[&str] = &[];
+
+pub struct Problem;
+
+register!(2022, 7, Problem);
+register_generator!(2022, 7, Problem);
+
+impl Solver for Problem {
+    type Input<'a> = FS<'a>;
+    /// The total size of every directory, leaving out those with a directory
+    /// that was never listed inside
+    type Prepared<'a> = HashMap<Path<'a>, usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let mut fs = HashMap::new();
+        let mut pwd = Vec::new();
+        for l in raw_input.split('$').skip(1) {
+            let command = l.trim().lines().next().unwrap_or_default();
+            match command {
+                "ls" => {
+                    let entries = l
+                        .lines()
+                        .skip(1)
+                        .map(|output| {
+                            let (size, f) = output.split_once(' ').ok_or_else(|| {
+                                ParseError::at(raw_input, output, "a file or dir")
+                            })?;
+                            let size = match size {
+                                "dir" => 0,
+                                size => parse_token(raw_input, size, "a file size")?,
+                            };
+                            Ok((size, f))
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    fs.entry(pwd.clone())
+                        .or_insert(HashSet::new())
+                        .extend(entries);
+                }
+                "cd .." => {
+                    pwd.pop();
+                }
+                cd_dir => {
+                    let dir = cd_dir
+                        .strip_prefix("cd ")
+                        .ok_or_else(|| ParseError::at(raw_input, command, "`cd` or `ls`"))?;
+                    match dir {
+                        "/" => pwd.clear(),
+                        dir => pwd.push(dir),
+                    }
+                }
+            }
+        }
+
+        Ok(fs)
+    }
+
+    fn prepare<'a>(&self, input: &Self::Input<'a>) -> Self::Prepared<'a> {
+        let mut sizes = HashMap::new();
+        for dir in input.keys() {
+            dir_size(input, dir, &mut sizes);
+        }
+        sizes
+    }
+
+    fn solve_first(
+        &self,
+        _input: &Self::Input<'_>,
+        sizes: &Self::Prepared<'_>,
+    ) -> Option<Self::Output1> {
+        Some(sizes.values().filter(|&&size| size < 100000).sum())
+    }
+
+    fn solve_second(
+        &self,
+        _input: &Self::Input<'_>,
+        sizes: &Self::Prepared<'_>,
+    ) -> Option<Self::Output2> {
+        let total_size = sizes.get(ROOT)?;
+
+        sizes
+            .values()
+            .copied()
+            .filter(|&size| 40000000 + size >= *total_size)
+            .min()
+    }
+}
+
+/// The size of `dir`, remembering it and the sizes of its subdirectories in
+/// `sizes` so every directory is only walked once. `None` when `dir` or one of
+/// its subdirectories was never listed.
+fn dir_size<'a>(
+    fs: &FS<'a>,
+    dir: &[&'a str],
+    sizes: &mut HashMap<Path<'a>, usize>,
+) -> Option<usize> {
+    if let Some(size) = sizes.get(dir) {
+        return Some(*size);
+    }
+    let files = fs.get(dir)?;
+    let size = files
+        .iter()
+        .map(|(size, name)| match size {
+            0 => dir_size(fs, &[dir, &[*name]].concat(), sizes),
+            x => Some(*x),
+        })
+        .sum::<Option<usize>>()?;
+    sizes.insert(dir.to_vec(), size);
+    Some(size)
+}
+
+/// A transcript exploring a random tree of `size` directories, listing each of
+/// them once
+impl InputGenerator for Problem {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut subdirs = vec![Vec::new(); size.max(1)];
+        for dir in 1..subdirs.len() {
+            let parent = rng.below(dir);
+            subdirs[parent].push(dir);
+        }
+
+        let mut transcript = vec![String::from("$ cd /")];
+        list_dir(rng, &subdirs, 0, &mut transcript);
+        transcript.join("\n")
+    }
+
+    fn real_size(&self) -> usize {
+        180
+    }
+}
+
+/// Appends the `ls` of `dir` and the exploration of its subdirectories
+fn list_dir(rng: &mut Rng, subdirs: &[Vec<usize>], dir: usize, transcript: &mut Vec<String>) {
+    let mut names = HashSet::new();
+    let mut new_name = |rng: &mut Rng, extension: &str| loop {
+        let letters: String = (0..rng.range(1..=8))
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect();
+        let name = letters + extension;
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let dirs: Vec<(String, usize)> = subdirs[dir]
+        .iter()
+        .map(|&subdir| (new_name(rng, ""), subdir))
+        .collect();
+    let mut entries: Vec<(String, String)> = dirs
+        .iter()
+        .map(|(name, _)| (name.clone(), format!("dir {}", name)))
+        .collect();
+    for _ in 0..rng.range(0..=4) {
+        let extension = *rng.choose(&["", ".txt", ".dat", ".log"]);
+        let name = new_name(rng, extension);
+        let size = rng.range(1000..=300000);
+        entries.push((name.clone(), format!("{} {}", size, name)));
+    }
+    entries.sort_unstable();
+
+    transcript.push(String::from("$ ls"));
+    transcript.extend(entries.into_iter().map(|(_, entry)| entry));
+    for (name, subdir) in dirs {
+        transcript.push(format!("$ cd {}", name));
+        list_dir(rng, subdirs, subdir, transcript);
+        transcript.push(String::from("$ cd .."));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlisted_dir() {
+        let input = Problem {}
+            .parse_input("$ cd /\n$ ls\ndir a\ndir b\n$ cd a\n$ ls\n10 f")
+            .unwrap();
+        let sizes = Problem {}.prepare(&input);
+        assert_eq!(sizes, HashMap::from([(vec!["a"], 10)]));
+        assert_eq!(Problem {}.solve_first(&input, &sizes), Some(10));
+        assert_eq!(Problem {}.solve_second(&input, &sizes), None);
+    }
+}
@@ -1,4 +1,7 @@
-use crate::solver::Solver;
+use aoc_core::generate::{InputGenerator, Rng};
+use aoc_core::parse::ParseError;
+use aoc_core::solver::Solver;
+use aoc_core::{register, register_generator};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Grid {
@@ -59,8 +62,12 @@ impl Grid {
 
     fn highest_scenic_score(&self) -> usize {
         (0..self.h)
-            .flat_map(|e| std::iter::repeat(e).zip(0..self.w))
+            .flat_map(|y| (0..self.w).map(move |x| (x, y)))
             .map(|(x, y)| {
+                // border trees see nothing in at least one direction
+                if x == 0 || y == 0 || x == self.w - 1 || y == self.h - 1 {
+                    return 0;
+                }
                 let mut score = 1;
                 let val = self.get((x, y)).unwrap();
                 // check left
@@ -122,49 +129,58 @@ impl Grid {
 
 pub struct Problem;
 
+register!(2022, 8, Problem);
+register_generator!(2022, 8, Problem);
+
 impl Solver for Problem {
-    type Input = Grid;
-    type Output = usize;
+    type Input<'a> = Grid;
+    type Prepared<'a> = ();
+    type Output1 = usize;
+    type Output2 = usize;
 
-    fn parse_input(&self, raw_input: String) -> Option<Self::Input> {
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
         let lines: Vec<&str> = raw_input.lines().collect();
         let h = lines.len();
-        let w = lines[0].len();
+        let w = lines
+            .first()
+            .ok_or_else(|| ParseError::end_of_input(raw_input, "a row of trees"))?
+            .len();
+        if let Some(l) = lines.iter().find(|l| l.len() != w) {
+            return Err(ParseError::at(
+                raw_input,
+                l,
+                format!("a row of {} trees", w),
+            ));
+        }
         let cells: Vec<char> = lines.iter().flat_map(|s| s.chars()).collect();
 
-        Some(Grid { cells, h, w })
+        Ok(Grid { cells, h, w })
     }
 
-    fn solve_first(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn solve_first(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output1> {
         Some(input.visible())
     }
 
-    fn solve_second(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn solve_second(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output2> {
         Some(input.highest_scenic_score())
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE: &str = r#"30373
-25512
-65332
-33549
-35390"#;
-
-    #[test]
-    fn test_first() {
-        let input = Problem {}.parse_input(String::from(EXAMPLE)).unwrap();
-        let result = Problem {}.solve_first(&input);
-        assert_eq!(result, Some(21));
+/// A square forest `size` trees wide
+impl InputGenerator for Problem {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let rows: Vec<String> = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| (b'0' + rng.below(10) as u8) as char)
+                    .collect()
+            })
+            .collect();
+        rows.join("\n")
     }
 
-    #[test]
-    fn test_second() {
-        let input = Problem {}.parse_input(String::from(EXAMPLE)).unwrap();
-        let result = Problem {}.solve_second(&input);
-        assert_eq!(result, Some(8));
+    fn real_size(&self) -> usize {
+        99
     }
 }
@@ -3,7 +3,10 @@ use std::{
     fmt::{self, Display, Formatter},
 };
 
-use crate::solver::Solver;
+use aoc_core::generate::{InputGenerator, Rng};
+use aoc_core::parse::{parse_token, ParseError};
+use aoc_core::solver::Solver;
+use aoc_core::{register, register_generator};
 
 #[derive(Debug, Clone, Copy)]
 enum Direction {
@@ -62,44 +65,49 @@ impl Coord {
         // Assume not adjacent
         let new_x = self.x + (other.x - self.x).signum();
         let new_y = self.y + (other.y - self.y).signum();
-        return Self { x: new_x, y: new_y };
+        Self { x: new_x, y: new_y }
     }
 }
 
 pub struct Problem;
 
+register!(2022, 9, Problem);
+register_generator!(2022, 9, Problem);
+
 impl Solver for Problem {
-    type Input = Vec<Movement>;
-    type Output = usize;
-
-    fn parse_input(&self, raw_input: String) -> Option<Self::Input> {
-        Some(
-            raw_input
-                .lines()
-                .map(|l| {
-                    let (direction, steps) = l.split_once(" ").unwrap();
-                    let direction = match direction {
-                        "R" => Direction::Right,
-                        "L" => Direction::Left,
-                        "D" => Direction::Down,
-                        "U" => Direction::Up,
-                        _ => unreachable!(),
-                    };
-                    Movement {
-                        direction,
-                        steps: steps.parse().unwrap(),
-                    }
+    type Input<'a> = Vec<Movement>;
+    type Prepared<'a> = ();
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        raw_input
+            .lines()
+            .map(|l| {
+                let (direction, steps) = l
+                    .split_once(' ')
+                    .ok_or_else(|| ParseError::at(raw_input, l, "a direction and steps"))?;
+                let direction = match direction {
+                    "R" => Direction::Right,
+                    "L" => Direction::Left,
+                    "D" => Direction::Down,
+                    "U" => Direction::Up,
+                    d => return Err(ParseError::at(raw_input, d, "one of R, L, D or U")),
+                };
+                Ok(Movement {
+                    direction,
+                    steps: parse_token(raw_input, steps, "a number of steps")?,
                 })
-                .collect(),
-        )
+            })
+            .collect()
     }
 
-    fn solve_first(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn solve_first(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output1> {
         let mut visited = HashSet::<Coord>::new();
         let mut head_position = Coord { x: 0, y: 0 };
         let mut tail_position = Coord { x: 0, y: 0 };
 
-        for movement in input.into_iter() {
+        for movement in input.iter() {
             for _ in 0..movement.steps {
                 (head_position, tail_position) =
                     move_rope(head_position, tail_position, &movement.direction);
@@ -109,11 +117,11 @@ impl Solver for Problem {
         Some(visited.len())
     }
 
-    fn solve_second(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn solve_second(&self, input: &Self::Input<'_>, _: &()) -> Option<Self::Output2> {
         let mut visited = HashSet::<Coord>::new();
         let mut rope = vec![Coord { x: 0, y: 0 }; 10];
 
-        for movement in input.into_iter() {
+        for movement in input.iter() {
             for _ in 0..movement.steps {
                 move_long_rope(&mut rope, &movement.direction);
                 visited.insert(*rope.last().unwrap());
@@ -123,8 +131,28 @@ impl Solver for Problem {
     }
 }
 
+/// `size` motions of the head
+impl InputGenerator for Problem {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let motions: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}",
+                    rng.choose(&["R", "L", "U", "D"]),
+                    rng.range(1..=20)
+                )
+            })
+            .collect();
+        motions.join("\n")
+    }
+
+    fn real_size(&self) -> usize {
+        2000
+    }
+}
+
 fn move_rope(head_position: Coord, tail_position: Coord, direction: &Direction) -> (Coord, Coord) {
-    let new_head_position = head_position.move_once(&direction);
+    let new_head_position = head_position.move_once(direction);
 
     let new_tail_position = match tail_position.is_adjacent_to(&new_head_position) {
         true => tail_position,
@@ -134,8 +162,8 @@ fn move_rope(head_position: Coord, tail_position: Coord, direction: &Direction)
     (new_head_position, new_tail_position)
 }
 
-fn move_long_rope(rope: &mut Vec<Coord>, direction: &Direction) {
-    let new_head_position = rope.first().unwrap().move_once(&direction);
+fn move_long_rope(rope: &mut [Coord], direction: &Direction) {
+    let new_head_position = rope.first().unwrap().move_once(direction);
     rope[0] = new_head_position;
 
     for i in 1..rope.len() {
@@ -148,46 +176,3 @@ fn move_long_rope(rope: &mut Vec<Coord>, direction: &Direction) {
         rope[i] = new_tail;
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE: &str = r#"R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2"#;
-
-    #[test]
-    fn test_first() {
-        let input = Problem {}.parse_input(String::from(EXAMPLE)).unwrap();
-        let result = Problem {}.solve_first(&input);
-        assert_eq!(result, Some(13));
-    }
-
-    #[test]
-    fn test_second() {
-        let input = Problem {}.parse_input(String::from(EXAMPLE)).unwrap();
-        let result = Problem {}.solve_second(&input);
-        assert_eq!(result, Some(1));
-    }
-
-    #[test]
-    fn test_second_complex() {
-        let example = r#"R 5
-U 8
-L 8
-D 3
-R 17
-D 10
-L 25
-U 20"#;
-        let input = Problem {}.parse_input(String::from(example)).unwrap();
-        let result = Problem {}.solve_second(&input);
-        assert_eq!(result, Some(36));
-    }
-}
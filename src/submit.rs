@@ -0,0 +1,310 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs,
+    io::ErrorKind,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+
+pub const SUBMISSIONS_PATH: &str = "submissions.toml";
+
+/// What the site said about a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Too many answers in a short time, see [`rate_limit_wait`]
+    RateLimited,
+    /// The part was solved already, or the previous one is still missing
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    /// Reads the verdict from the HTML page returned after submitting
+    pub fn parse(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Verdict::Right
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if page.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Verdict::RateLimited
+        } else if page.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Verdict::Right => write!(f, "That's the right answer!"),
+            Verdict::Wrong => write!(f, "That's not the right answer"),
+            Verdict::TooHigh => write!(f, "That's not the right answer, it is too high"),
+            Verdict::TooLow => write!(f, "That's not the right answer, it is too low"),
+            Verdict::RateLimited => write!(f, "You gave an answer too recently"),
+            Verdict::WrongLevel => {
+                write!(f, "This part is not open, it may be solved already")
+            }
+            Verdict::Unknown => write!(f, "The response could not be understood"),
+        }
+    }
+}
+
+/// How long a rate limited page asks to wait, like `1m 2s`
+pub fn rate_limit_wait(page: &str) -> Option<&str> {
+    let (_, rest) = page.split_once("You have ")?;
+    Some(rest.split_once(" left to wait")?.0)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: i32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch
+    pub time: u64,
+}
+
+/// Every answer submitted so far, as stored in `submissions.toml`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmissionLog {
+    #[serde(default, rename = "attempt")]
+    attempts: Vec<Attempt>,
+}
+
+impl SubmissionLog {
+    /// Loads the log, a missing file has no attempts yet
+    pub fn load(path: &str) -> Result<Self, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(raw) => Ok(toml::from_str(&raw).map_err(|e| format!("{}: {}", path, e))?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {}", path, e).into()),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn push(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    fn attempts(&self, year: i32, day: u32, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.year == year && a.day == day && a.part == part)
+    }
+
+    /// Rejects answers that can't be right from what earlier attempts showed,
+    /// so they are not sent at all
+    pub fn check(&self, year: i32, day: u32, part: u8, answer: &str) -> Result<(), String> {
+        let mut lowest_too_high: Option<i128> = None;
+        let mut highest_too_low: Option<i128> = None;
+
+        for attempt in self.attempts(year, day, part) {
+            match attempt.verdict {
+                Verdict::Right => {
+                    return Err(format!("Already solved, the answer was {}", attempt.answer))
+                }
+                verdict if verdict.is_wrong() && attempt.answer == answer => {
+                    return Err(format!("{} was already submitted: {}", answer, verdict))
+                }
+                Verdict::TooHigh => {
+                    if let Ok(n) = attempt.answer.parse() {
+                        lowest_too_high = Some(lowest_too_high.map_or(n, |h| h.min(n)));
+                    }
+                }
+                Verdict::TooLow => {
+                    if let Ok(n) = attempt.answer.parse() {
+                        highest_too_low = Some(highest_too_low.map_or(n, |l| l.max(n)));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let Ok(n) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        match (lowest_too_high, highest_too_low) {
+            (Some(high), _) if n >= high => Err(format!(
+                "{} can't be right, {} was already too high",
+                answer, high
+            )),
+            (_, Some(low)) if n <= low => Err(format!(
+                "{} can't be right, {} was already too low",
+                answer, low
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub verdict: Verdict,
+    /// How long to wait before the next attempt, when rate limited
+    pub wait: Option<String>,
+}
+
+/// Submits `answer` unless the log shows it can't be right, and logs the
+/// attempt with its verdict
+pub fn submit(
+    client: &Client,
+    log: &mut SubmissionLog,
+    (year, day, part): (i32, u32, u8),
+    answer: &str,
+    now: SystemTime,
+) -> Result<Outcome, Box<dyn Error>> {
+    log.check(year, day, part, answer)?;
+    let page = client.submit_answer(year, day, part, answer, now)?;
+    let verdict = Verdict::parse(&page);
+
+    log.push(Attempt {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+        time: now.duration_since(UNIX_EPOCH)?.as_secs(),
+    });
+    Ok(Outcome {
+        verdict,
+        wait: rate_limit_wait(&page).map(String::from),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{mock::serve_once, Config};
+
+    fn attempt(answer: &str, verdict: Verdict) -> Attempt {
+        Attempt {
+            year: 2022,
+            day: 1,
+            part: 1,
+            answer: answer.to_string(),
+            verdict,
+            time: 0,
+        }
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let page = |s| format!("<main><article><p>{}</p></article></main>", s);
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Verdict::Right
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too high. If you're stuck..."
+            )),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer. If you're stuck...")),
+            Verdict::Wrong
+        );
+        let rate_limited = page(
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again.  You have 34s left to wait.",
+        );
+        assert_eq!(Verdict::parse(&rate_limited), Verdict::RateLimited);
+        assert_eq!(rate_limit_wait(&rate_limited), Some("34s"));
+        assert_eq!(
+            Verdict::parse(&page("You don't seem to be solving the right level.")),
+            Verdict::WrongLevel
+        );
+        assert_eq!(Verdict::parse("<html></html>"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut log = SubmissionLog::default();
+        log.push(attempt("500", Verdict::TooHigh));
+        log.push(attempt("700", Verdict::TooHigh));
+        log.push(attempt("100", Verdict::TooLow));
+        log.push(attempt("250", Verdict::Wrong));
+        log.push(attempt("300", Verdict::RateLimited));
+
+        assert!(log.check(2022, 1, 1, "250").is_err());
+        assert!(log.check(2022, 1, 1, "500").is_err());
+        assert!(log.check(2022, 1, 1, "600").is_err());
+        assert!(log.check(2022, 1, 1, "100").is_err());
+        assert!(log.check(2022, 1, 1, "300").is_ok());
+        assert!(log.check(2022, 1, 1, "ABC").is_ok());
+        assert!(log.check(2022, 1, 2, "600").is_ok());
+
+        log.push(attempt("300", Verdict::Right));
+        assert_eq!(
+            log.check(2022, 1, 1, "301"),
+            Err(String::from("Already solved, the answer was 300"))
+        );
+    }
+
+    #[test]
+    fn test_log_roundtrip() {
+        let mut log = SubmissionLog::default();
+        log.push(attempt("500", Verdict::TooHigh));
+        log.push(attempt("300", Verdict::RateLimited));
+
+        let raw = toml::to_string(&log).unwrap();
+        assert!(raw.starts_with("[[attempt]]"));
+        assert_eq!(toml::from_str::<SubmissionLog>(&raw).unwrap(), log);
+    }
+
+    #[test]
+    fn test_submit() {
+        let page = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+        let (base_url, server) = serve_once("200 OK", page);
+        let client = Client::new(Config {
+            session: Some(String::from("cafe")),
+            base_url: Some(base_url),
+        });
+        let mut log = SubmissionLog::default();
+
+        let outcome = submit(&client, &mut log, (2022, 1, 2), "41", SystemTime::now()).unwrap();
+        assert_eq!(outcome.verdict, Verdict::TooLow);
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/1/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=41"));
+
+        // Known to be too low, so it is rejected before reaching the server
+        assert!(submit(&client, &mut log, (2022, 1, 2), "40", SystemTime::now()).is_err());
+        assert_eq!(log.attempts(2022, 1, 2).count(), 1);
+    }
+}
@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+
+use aoc_core::parse::ParseError;
+use aoc_core::register;
+use aoc_core::solver::Solver;
+
+pub struct Problem;
+
+register!(__YEAR__, __DAY__, Problem);
+
+impl Solver for Problem {
+    /// Neighbours of every node, from lines like `a: b c`
+    type Input<'a> = HashMap<&'a str, Vec<&'a str>>;
+    type Prepared<'a> = ();
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        raw_input
+            .lines()
+            .map(|l| {
+                let (node, neighbours) = l
+                    .split_once(':')
+                    .ok_or_else(|| ParseError::at(raw_input, l, "a node and its neighbours"))?;
+                Ok((node.trim(), neighbours.split_whitespace().collect()))
+            })
+            .collect()
+    }
+
+    fn solve_first(&self, _input: &Self::Input<'_>, _: &()) -> Option<Self::Output1> {
+        None
+    }
+
+    fn solve_second(&self, _input: &Self::Input<'_>, _: &()) -> Option<Self::Output2> {
+        None
+    }
+}
@@ -0,0 +1,39 @@
+use aoc_core::parse::ParseError;
+use aoc_core::register;
+use aoc_core::solver::Solver;
+use aoc_grid::Grid;
+
+pub struct Problem;
+
+register!(__YEAR__, __DAY__, Problem);
+
+impl Solver for Problem {
+    type Input<'a> = Grid<char>;
+    type Prepared<'a> = ();
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let lines: Vec<&str> = raw_input.lines().collect();
+        let h = lines.len();
+        let w = lines.first().map_or(0, |l| l.len());
+        if let Some(l) = lines.iter().find(|l| l.len() != w) {
+            return Err(ParseError::at(
+                raw_input,
+                l,
+                format!("a row of length {}", w),
+            ));
+        }
+
+        let cells = lines.iter().flat_map(|l| l.chars()).collect();
+        Ok(Grid::new(cells, h, w))
+    }
+
+    fn solve_first(&self, _input: &Self::Input<'_>, _: &()) -> Option<Self::Output1> {
+        None
+    }
+
+    fn solve_second(&self, _input: &Self::Input<'_>, _: &()) -> Option<Self::Output2> {
+        None
+    }
+}
@@ -0,0 +1,26 @@
+use aoc_core::parse::ParseError;
+use aoc_core::register;
+use aoc_core::solver::Solver;
+
+pub struct Problem;
+
+register!(__YEAR__, __DAY__, Problem);
+
+impl Solver for Problem {
+    type Input<'a> = Vec<&'a str>;
+    type Prepared<'a> = ();
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse_input<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Ok(raw_input.lines().collect())
+    }
+
+    fn solve_first(&self, _input: &Self::Input<'_>, _: &()) -> Option<Self::Output1> {
+        None
+    }
+
+    fn solve_second(&self, _input: &Self::Input<'_>, _: &()) -> Option<Self::Output2> {
+        None
+    }
+}
@@ -0,0 +1,238 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use aoc_core::{
+    fixtures::{self, Fixture, EXAMPLES_DIR},
+    runner::{self, outcomes, InputSource, Outcome},
+    solver::Answer,
+};
+
+/// How often the watched files are checked for changes
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// How long a part may run before it is reported as timed out, so a change
+/// that makes it loop forever doesn't stop the watch
+pub const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// When each watched file was last modified and its size, files that don't
+/// exist are left out
+pub type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+/// The input of `day` and every file of its fixtures, whether they exist or not
+fn watched_paths(year: i32, day: u32) -> Vec<PathBuf> {
+    let mut paths = vec![PathBuf::from(runner::input_path(year, day))];
+    let dir = fixtures::day_dir(Path::new(EXAMPLES_DIR), year, day);
+    if let Ok(entries) = fs::read_dir(dir) {
+        paths.extend(entries.filter_map(|entry| Some(entry.ok()?.path())));
+    }
+    paths
+}
+
+pub fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    paths
+        .iter()
+        .filter_map(|path| {
+            let metadata = fs::metadata(path).ok()?;
+            Some((path.clone(), (metadata.modified().ok()?, metadata.len())))
+        })
+        .collect()
+}
+
+/// The files that were created, modified or removed between two snapshots
+pub fn changed(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let paths: BTreeSet<&PathBuf> = before.keys().chain(after.keys()).collect();
+    paths
+        .into_iter()
+        .filter(|path| before.get(*path) != after.get(*path))
+        .cloned()
+        .collect()
+}
+
+/// One part of the watched day on one of its inputs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub input: String,
+    pub part: u8,
+    pub outcome: Outcome,
+    /// What the fixture expects, for examples
+    pub expected: Option<String>,
+}
+
+/// Runs the day on `source`
+fn run_source(year: i32, day: u32, source: &InputSource) -> [Outcome; 2] {
+    match runner::solve(year, day, None, source, &[], Some(TIMEOUT)) {
+        Some(solved) => outcomes(solved),
+        None => {
+            let e = format!("Day {} of {} has not been solved yet", day, year);
+            [Outcome::Error(e.clone()), Outcome::Error(e)]
+        }
+    }
+}
+
+/// Runs the day on its input, if there is one, and on every fixture
+pub fn run(year: i32, day: u32) -> Vec<Line> {
+    let mut lines = Vec::new();
+    for source in runner::sources(year, day) {
+        let expected = match &source {
+            InputSource::Example(name) => Fixture::load(Path::new(EXAMPLES_DIR), year, day, name)
+                .map(|f| f.expected)
+                .unwrap_or_default(),
+            _ => [None, None],
+        };
+        let input = source.describe(year, day);
+        let outcomes = run_source(year, day, &source);
+        for ((part, outcome), expected) in (1..=2).zip(outcomes).zip(expected) {
+            lines.push(Line {
+                input: input.clone(),
+                part,
+                outcome,
+                expected,
+            });
+        }
+    }
+    lines
+}
+
+/// The lines of a run, each with how it differs from the same input and part
+/// in the previous run and from what its fixture expects
+pub fn report(previous: &[Line], lines: &[Line]) -> String {
+    let width = lines.iter().map(|l| l.input.len()).max().unwrap_or(0);
+    let mut report = String::new();
+    for line in lines {
+        let mut notes = Vec::new();
+        if let (Outcome::Answer(answer), Some(expected)) = (&line.outcome, &line.expected) {
+            match answer.as_ref().map(Answer::to_string) {
+                Some(answer) if answer == *expected => notes.push(String::from("ok")),
+                _ => notes.push(format!("expected {}", expected)),
+            }
+        }
+        let before = previous
+            .iter()
+            .find(|p| p.input == line.input && p.part == line.part);
+        if let Some(before) = before.filter(|b| b.outcome != line.outcome) {
+            notes.push(format!("was {}", before.outcome));
+        }
+
+        report.push_str(&format!(
+            "{:<width$}  part {}: {}",
+            line.input, line.part, line.outcome
+        ));
+        if !notes.is_empty() {
+            report.push_str(&format!("  ({})", notes.join(", ")));
+        }
+        report.push('\n');
+    }
+    report
+}
+
+/// Re-runs the day every time its input or fixtures change, until interrupted.
+/// A timed out part can't be stopped, so the next run waits until its worker
+/// is done instead of leaving one more running on every change.
+pub fn watch(year: i32, day: u32) -> ! {
+    let mut seen: Option<Snapshot> = None;
+    let mut previous = Vec::new();
+    let mut waiting = false;
+    loop {
+        let now = snapshot(&watched_paths(year, day));
+        if seen.as_ref() != Some(&now) {
+            let leftover = runner::leftover_workers();
+            if !leftover.is_empty() {
+                if !waiting {
+                    println!(
+                        "Waiting for {} to finish, it timed out and can't be stopped\n",
+                        leftover.join(", ")
+                    );
+                }
+                waiting = true;
+                thread::sleep(POLL_INTERVAL);
+                continue;
+            }
+            waiting = false;
+
+            if let Some(seen) = &seen {
+                for path in changed(seen, &now) {
+                    println!("Changed {}", path.display());
+                }
+            }
+            let lines = run(year, day);
+            match lines.is_empty() {
+                true => println!("Day {} of {} has no input or examples yet", day, year),
+                false => print!("{}", report(&previous, &lines)),
+            }
+            println!();
+            previous = lines;
+            seen = Some(now);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::solver::Failure;
+    use std::env;
+
+    fn line(input: &str, part: u8, outcome: Outcome, expected: Option<&str>) -> Line {
+        Line {
+            input: input.to_string(),
+            part,
+            outcome,
+            expected: expected.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_report() {
+        let answer = |n| Outcome::Answer(Some(Answer::Integer(n)));
+        let previous = vec![
+            line("inputs/2022/day9.txt", 1, answer(6022), None),
+            line("inputs/2022/day9.txt", 2, answer(2500), None),
+            line("examples/2022/day9/example.txt", 1, answer(13), Some("13")),
+        ];
+        let lines = vec![
+            line("inputs/2022/day9.txt", 1, answer(6023), None),
+            line("inputs/2022/day9.txt", 2, answer(2500), None),
+            line(
+                "examples/2022/day9/example.txt",
+                1,
+                Outcome::Failure(Failure::Panic(String::from(
+                    "attempt to subtract with overflow",
+                ))),
+                Some("13"),
+            ),
+            line("examples/2022/day9/example.txt", 2, answer(2), Some("1")),
+        ];
+        let expected = "\
+inputs/2022/day9.txt            part 1: 6023  (was 6022)
+inputs/2022/day9.txt            part 2: 2500
+examples/2022/day9/example.txt  part 1: PANIC attempt to subtract with overflow  (was 13)
+examples/2022/day9/example.txt  part 2: 2  (expected 1)
+";
+        assert_eq!(report(&previous, &lines), expected);
+    }
+
+    #[test]
+    fn test_changed() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let paths = vec![dir.join("example.txt"), dir.join("input.txt")];
+        fs::write(&paths[1], "1\n").unwrap();
+
+        let before = snapshot(&paths);
+        assert!(changed(&before, &snapshot(&paths)).is_empty());
+        fs::write(&paths[0], "3\n").unwrap();
+        fs::write(&paths[1], "1\n2\n").unwrap();
+        let after = snapshot(&paths);
+        assert_eq!(changed(&before, &after), paths);
+        fs::remove_file(&paths[0]).unwrap();
+        assert_eq!(changed(&after, &snapshot(&paths)), vec![paths[0].clone()]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,26 @@
+//! Runs every fixture in `examples/`, one test each, generated by `build.rs`
+
+use std::path::PathBuf;
+
+use aoc_core::{
+    fixtures::{self, EXAMPLES_DIR},
+    registry,
+};
+// Linked for the solvers it registers
+use aoc_solutions as _;
+
+fn examples_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(EXAMPLES_DIR)
+}
+
+include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+
+#[test]
+fn test_every_day_has_an_example() {
+    let missing: Vec<(i32, u32)> = registry::years()
+        .into_iter()
+        .flat_map(|year| registry::days(year).into_iter().map(move |day| (year, day)))
+        .filter(|&(year, day)| fixtures::names(&examples_dir(), year, day).is_empty())
+        .collect();
+    assert_eq!(missing, vec![]);
+}
@@ -0,0 +1,68 @@
+//! Runs every day with an input generator on a few of its inputs, which must
+//! parse and solve without a failure
+
+use aoc_core::{generate, registry};
+// Linked for the solvers and generators it registers
+use aoc_solutions as _;
+
+/// Solves the input of `day` from `seed`, of `size` or a real one's
+fn check(day: u32, seed: u64, size: Option<usize>) {
+    let solver = (registry::get(2022, day).unwrap().solver)();
+    let input = generate::generate(2022, day, seed, size).unwrap();
+    let results = solver
+        .run(2022, day, input.clone(), None)
+        .unwrap_or_else(|e| {
+            panic!(
+                "day {} seed {} size {:?}: {}\n{}",
+                day, seed, size, e, input
+            )
+        });
+    for result in results {
+        assert!(
+            result.failure.is_none() && result.answer.is_some(),
+            "day {} seed {} size {:?} part {}: {:?}\n{}",
+            day,
+            seed,
+            size,
+            result.part,
+            result.failure,
+            input
+        );
+    }
+}
+
+#[test]
+fn test_generated_inputs_solve() {
+    assert_eq!(generate::days(2022), (1..=16).collect::<Vec<_>>());
+
+    for day in generate::days(2022) {
+        for seed in 0..5 {
+            check(day, seed, Some(2));
+            check(day, seed, Some(12));
+        }
+    }
+}
+
+#[test]
+#[ignore = "takes minutes in debug, run with `cargo test --release -- --ignored`"]
+fn test_real_size_inputs_solve() {
+    for day in generate::days(2022) {
+        for seed in 0..5 {
+            check(day, seed, None);
+        }
+    }
+}
+
+#[test]
+fn test_generated_inputs_are_reproducible() {
+    for day in generate::days(2022) {
+        assert_eq!(
+            generate::generate(2022, day, 7, None),
+            generate::generate(2022, day, 7, None)
+        );
+        assert_ne!(
+            generate::generate(2022, day, 7, None),
+            generate::generate(2022, day, 8, None)
+        );
+    }
+}